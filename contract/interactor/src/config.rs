@@ -1,4 +1,4 @@
-#![allow(unused)]
+#![allow(unused, clippy::new_without_default)]
 
 use serde::Deserialize;
 use std::io::Read;
@@ -5,7 +5,7 @@ mod proxy;
 
 use config::Config;
 use multiversx_sc_snippets::imports::*;
-use proxy::{Question, Answer};
+use proxy::{Answer, Badge, Proposal, Question, QueuedAdminAction};
 use serde::{Deserialize, Serialize};
 use std::{
     io::{Read, Write},
@@ -34,12 +34,136 @@ pub async fn stacktoken_cli() {
         "getAnswersForQuestion" => interact.get_answers_for_question().await,
         "pauseContract" => interact.pause_contract().await,
         "unpauseContract" => interact.unpause_contract().await,
-        "transferOwnership" => interact.transfer_ownership().await,
         "getOwner" => interact.get_owner().await,
         "isPaused" => interact.is_contract_paused().await,
         "getTotalQuestions" => interact.get_total_questions().await,
         "getTotalAnswers" => interact.get_total_answers().await,
         "getContractStats" => interact.get_contract_stats().await,
+        "addToDenylist" => interact.add_to_denylist().await,
+        "removeFromDenylist" => interact.remove_from_denylist().await,
+        "setUserLimits" => interact.set_user_limits().await,
+        "getModerators" => interact.get_moderators().await,
+        "isModerator" => interact.is_moderator().await,
+        "isDenylisted" => interact.is_denylisted().await,
+        "getUserLimits" => interact.get_user_limits().await,
+        "getOpenQuestionCount" => interact.get_open_question_count().await,
+        "setAnswerStake" => interact.set_answer_stake().await,
+        "markAnswerAsSpam" => interact.mark_answer_as_spam().await,
+        "getAnswerStake" => interact.get_answer_stake().await,
+        "getAnswerStakeDeposit" => interact.get_answer_stake_deposit().await,
+        "isAnswerSpam" => interact.is_answer_spam().await,
+        "getTreasury" => interact.get_treasury().await,
+        "commitAnswer" => interact.commit_answer().await,
+        "revealAnswer" => interact.reveal_answer().await,
+        "enableCommitReveal" => interact.enable_commit_reveal().await,
+        "getRevealPeriod" => interact.get_reveal_period().await,
+        "computeAnswerCommitment" => interact.compute_answer_commitment().await,
+        "isAnswerRevealed" => interact.is_answer_revealed().await,
+        "computeContentHash" => interact.compute_content_hash().await,
+        "getAnswerContentHash" => interact.get_answer_content_hash().await,
+        "getContentHashFirstAuthor" => interact.get_content_hash_first_author().await,
+        "claim" => interact.claim().await,
+        "setPullPayouts" => interact.set_pull_payouts().await,
+        "getClaimable" => interact.get_claimable().await,
+        "isPullPayoutsEnabled" => interact.is_pull_payouts_enabled().await,
+        "issueStackToken" => interact.issue_stack_token().await,
+        "setStackTokenRewards" => interact.set_stack_token_rewards().await,
+        "getStackTokenId" => interact.get_stack_token_id().await,
+        "getStackTokenRewards" => interact.get_stack_token_rewards().await,
+        "voteAnswer" => interact.vote_answer().await,
+        "issueBadgeCollection" => interact.issue_badge_collection().await,
+        "addBadge" => interact.add_badge().await,
+        "awardBadge" => interact.award_badge().await,
+        "getBadges" => interact.get_badges().await,
+        "getBadgeRegistry" => interact.get_badge_registry().await,
+        "getContributionStats" => interact.get_contribution_stats().await,
+        "stake" => interact.stake().await,
+        "unstake" => interact.unstake().await,
+        "withdrawUnstaked" => interact.withdraw_unstaked().await,
+        "setUnbondingPeriod" => interact.set_unbonding_period().await,
+        "setVoteWeightParams" => interact.set_vote_weight_params().await,
+        "getVoteWeight" => interact.get_vote_weight().await,
+        "getPlatformFeeFor" => interact.get_platform_fee_for().await,
+        "getStakedAmount" => interact.get_staked_amount().await,
+        "getUnbonding" => interact.get_unbonding().await,
+        "getTotalStaked" => interact.get_total_staked().await,
+        "getPlatformFee" => interact.get_platform_fee().await,
+        "proposeParameterChange" => interact.propose_parameter_change().await,
+        "voteProposal" => interact.vote_proposal().await,
+        "executeProposal" => interact.execute_proposal().await,
+        "setGovernanceParams" => interact.set_governance_params().await,
+        "getMinBounty" => interact.get_min_bounty().await,
+        "getDeadlineBounds" => interact.get_deadline_bounds().await,
+        "getProposal" => interact.get_proposal().await,
+        "getProposalStatus" => interact.get_proposal_status().await,
+        "getActiveProposals" => interact.get_active_proposals().await,
+        "getProposalVotes" => interact.get_proposal_votes().await,
+        "queueAdminAction" => interact.queue_admin_action().await,
+        "executeAdminAction" => interact.execute_admin_action().await,
+        "cancelAdminAction" => interact.cancel_admin_action().await,
+        "getPendingAdminActions" => interact.get_pending_admin_actions().await,
+        "getAdminTimelockDelay" => interact.get_admin_timelock_delay().await,
+        "emergencyRefundBatch" => interact.emergency_refund_batch().await,
+        "getEmergencyRefundProgress" => interact.get_emergency_refund_progress().await,
+        "getTokenTotals" => interact.get_token_totals().await,
+        "checkSolvency" => interact.check_solvency().await,
+        "tipAnswer" => interact.tip_answer().await,
+        "getAnswerTips" => interact.get_answer_tips().await,
+        "createSponsorPool" => interact.create_sponsor_pool().await,
+        "fundSponsorPool" => interact.fund_sponsor_pool().await,
+        "withdrawSponsorPool" => interact.withdraw_sponsor_pool().await,
+        "getSponsorPool" => interact.get_sponsor_pool().await,
+        "getPoolsByTag" => interact.get_pools_by_tag().await,
+        "getPoolMatches" => interact.get_pool_matches().await,
+        "getQuestionTags" => interact.get_question_tags().await,
+        "postMilestoneQuestion" => interact.post_milestone_question().await,
+        "approveMilestone" => interact.approve_milestone().await,
+        "getMilestones" => interact.get_milestones().await,
+        "assignQuestion" => interact.assign_question().await,
+        "acceptAssignment" => interact.accept_assignment().await,
+        "markDelivered" => interact.mark_delivered().await,
+        "disputeDelivery" => interact.dispute_delivery().await,
+        "releaseDelivery" => interact.release_delivery().await,
+        "resolveDispute" => interact.resolve_dispute().await,
+        "getJobAssignment" => interact.get_job_assignment().await,
+        "setReviewers" => interact.set_reviewers().await,
+        "getReviewers" => interact.get_reviewers().await,
+        "getReviewerApprovals" => interact.get_reviewer_approvals().await,
+        "postInviteOnlyQuestion" => interact.post_invite_only_question().await,
+        "addAllowedAnswerers" => interact.add_allowed_answerers().await,
+        "removeAllowedAnswerers" => interact.remove_allowed_answerers().await,
+        "canAnswer" => interact.can_answer().await,
+        "getAllowedAnswerers" => interact.get_allowed_answerers().await,
+        "postPuzzleQuestion" => interact.post_puzzle_question().await,
+        "commitPuzzleSolution" => interact.commit_puzzle_solution().await,
+        "solvePuzzle" => interact.solve_puzzle().await,
+        "computePuzzleCommitment" => interact.compute_puzzle_commitment().await,
+        "getPuzzleHash" => interact.get_puzzle_hash().await,
+        "postCompetitionQuestion" => interact.post_competition_question().await,
+        "rankAnswers" => interact.rank_answers().await,
+        "getPayoutSchedule" => interact.get_payout_schedule().await,
+        "postDecayingQuestion" => interact.post_decaying_question().await,
+        "getCurrentPayout" => interact.get_current_payout().await,
+        "getBountyDecay" => interact.get_bounty_decay().await,
+        "setVestingPeriod" => interact.set_vesting_period().await,
+        "claimVested" => interact.claim_vested().await,
+        "disputeVesting" => interact.dispute_vesting().await,
+        "resolveVestingDispute" => interact.resolve_vesting_dispute().await,
+        "getVesting" => interact.get_vesting().await,
+        "getVestedClaimable" => interact.get_vested_claimable().await,
+        "getVestingPeriod" => interact.get_vesting_period().await,
+        "addComment" => interact.add_comment().await,
+        "setCommentHidden" => interact.set_comment_hidden().await,
+        "getComments" => interact.get_comments().await,
+        "getCommentCount" => interact.get_comment_count().await,
+        "closeAsDuplicate" => interact.close_as_duplicate().await,
+        "setProfile" => interact.set_profile().await,
+        "getProfile" => interact.get_profile().await,
+        "getUsersBySkill" => interact.get_users_by_skill().await,
+        "getAddressByDisplayName" => interact.get_address_by_display_name().await,
+        "getStakeLockedUntil" => interact.get_stake_locked_until().await,
+        "claimBadges" => interact.claim_badges().await,
+        "getPendingBadges" => interact.get_pending_badges().await,
         _ => panic!("unknown command: {}", &cmd),
     }
 }
@@ -142,6 +266,7 @@ impl ContractInteract {
         let title = ManagedBuffer::new_from_bytes(&b""[..]);
         let description = ManagedBuffer::new_from_bytes(&b""[..]);
         let deadline = 0u64;
+        let tags = MultiValueVec::<ManagedBuffer<StaticApi>>::new();
 
         let response = self
             .interactor
@@ -150,7 +275,7 @@ impl ContractInteract {
             .to(self.state.current_address())
             .gas(30_000_000u64)
             .typed(proxy::StackTokenContractProxy)
-            .post_question(title, description, deadline)
+            .post_question(title, description, deadline, tags)
             .egld(egld_amount)
             .returns(ReturnsResultUnmanaged)
             .run()
@@ -160,6 +285,8 @@ impl ContractInteract {
     }
 
     pub async fn submit_answer(&mut self) {
+        let egld_amount = BigUint::<StaticApi>::from(0u128);
+
         let question_id = 0u64;
         let title = ManagedBuffer::new_from_bytes(&b""[..]);
         let description = ManagedBuffer::new_from_bytes(&b""[..]);
@@ -172,6 +299,7 @@ impl ContractInteract {
             .gas(30_000_000u64)
             .typed(proxy::StackTokenContractProxy)
             .submit_answer(question_id, title, description)
+            .egld(egld_amount)
             .returns(ReturnsResultUnmanaged)
             .run()
             .await;
@@ -254,9 +382,9 @@ impl ContractInteract {
         for (index, question) in questions_vec.iter().enumerate() {
             println!("Question {}:", index + 1);
             println!("  ID: {}", question.question_id);
-            println!("  Title: {}", question.title.to_string());
-            println!("  Description: {}", question.description.to_string());
-            println!("  Creator: {}", bech32::encode(&question.creator.to_address()));
+            println!("  Title: {}", question.title);
+            println!("  Description: {}", question.description);
+            println!("  Creator: {}", self.display_address(&question.creator).await);
             println!("  Deadline: {}", question.deadline);
             println!("  Locked Amount: {:?}", question.locked_amount);
             println!("  Created At: {}", question.created_at);
@@ -285,9 +413,9 @@ impl ContractInteract {
             Some(question) => {
                 println!("Question Details:");
                 println!("  ID: {}", question.question_id);
-                println!("  Title: {}", question.title.to_string());
-                println!("  Description: {}", question.description.to_string());
-                println!("  Creator: {}", bech32::encode(&question.creator.to_address()));
+                println!("  Title: {}", question.title);
+                println!("  Description: {}", question.description);
+                println!("  Creator: {}", self.display_address(&question.creator).await);
                 println!("  Deadline: {}", question.deadline);
                 println!("  Locked Amount: {:?}", question.locked_amount);
                 println!("  Created At: {}", question.created_at);
@@ -295,6 +423,9 @@ impl ContractInteract {
                 if let Some(approved_id) = question.approved_answer_id {
                     println!("  Approved Answer ID: {}", approved_id);
                 }
+                if let Some(canonical_id) = question.duplicate_of {
+                    println!("  Duplicate Of: {}", canonical_id);
+                }
             }
             None => {
                 println!("Question with ID {} not found", question_id);
@@ -302,6 +433,25 @@ impl ContractInteract {
         }
     }
 
+    // Shows the profile display name next to the address when one is set
+    async fn display_address(&mut self, address: &ManagedAddress<StaticApi>) -> String {
+        let bech32_address = bech32::encode(&address.to_address());
+        let profile = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::StackTokenContractProxy)
+            .get_profile(address)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        match profile.into_option() {
+            Some(profile) => format!("{} ({bech32_address})", profile.display_name),
+            None => bech32_address,
+        }
+    }
+
     pub async fn get_answers_for_question(&mut self) {
         let question_id = 0u64; // You can modify this to accept a parameter or read from input
 
@@ -327,9 +477,9 @@ impl ContractInteract {
             println!("Answer {}:", index + 1);
             println!("  Answer ID: {}", answer.answer_id);
             println!("  Question ID: {}", answer.question_id);
-            println!("  Title: {}", answer.title.to_string());
-            println!("  Description: {}", answer.description.to_string());
-            println!("  Creator: {}", bech32::encode(&answer.creator.to_address()));
+            println!("  Title: {}", answer.title);
+            println!("  Description: {}", answer.description);
+            println!("  Creator: {}", self.display_address(&answer.creator).await);
             println!("  Created At: {}", answer.created_at);
             println!("  Votes: {}", answer.votes);
             println!("  Approved by Creator: {}", answer.approved_by_creator);
@@ -369,8 +519,96 @@ impl ContractInteract {
         println!("Result: {response:?}");
     }
 
-    pub async fn transfer_ownership(&mut self) {
-        let new_owner = bech32::decode("");
+    pub async fn get_owner(&mut self) {
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::StackTokenContractProxy)
+            .get_owner()
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    pub async fn is_contract_paused(&mut self) {
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::StackTokenContractProxy)
+            .is_contract_paused()
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    pub async fn get_total_questions(&mut self) {
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::StackTokenContractProxy)
+            .get_total_questions()
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    pub async fn get_total_answers(&mut self) {
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::StackTokenContractProxy)
+            .get_total_answers()
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    pub async fn get_contract_stats(&mut self) {
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::StackTokenContractProxy)
+            .get_contract_stats()
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    pub async fn add_to_denylist(&mut self) {
+        let address = bech32::decode("");
+
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(proxy::StackTokenContractProxy)
+            .add_to_denylist(address)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {response:?}");
+    }
+
+    pub async fn remove_from_denylist(&mut self) {
+        let address = bech32::decode("");
 
         let response = self
             .interactor
@@ -379,7 +617,7 @@ impl ContractInteract {
             .to(self.state.current_address())
             .gas(30_000_000u64)
             .typed(proxy::StackTokenContractProxy)
-            .transfer_ownership(new_owner)
+            .remove_from_denylist(address)
             .returns(ReturnsResultUnmanaged)
             .run()
             .await;
@@ -387,13 +625,33 @@ impl ContractInteract {
         println!("Result: {response:?}");
     }
 
-    pub async fn get_owner(&mut self) {
+    pub async fn set_user_limits(&mut self) {
+        let max_open_questions = 0u64;
+        let max_answers_per_question = 0u32;
+        let post_cooldown = 0u64;
+
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(proxy::StackTokenContractProxy)
+            .set_user_limits(max_open_questions, max_answers_per_question, post_cooldown)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {response:?}");
+    }
+
+    pub async fn get_moderators(&mut self) {
         let result_value = self
             .interactor
             .query()
             .to(self.state.current_address())
             .typed(proxy::StackTokenContractProxy)
-            .get_owner()
+            .get_moderators()
             .returns(ReturnsResultUnmanaged)
             .run()
             .await;
@@ -401,13 +659,15 @@ impl ContractInteract {
         println!("Result: {result_value:?}");
     }
 
-    pub async fn is_contract_paused(&mut self) {
+    pub async fn is_moderator(&mut self) {
+        let address = bech32::decode("");
+
         let result_value = self
             .interactor
             .query()
             .to(self.state.current_address())
             .typed(proxy::StackTokenContractProxy)
-            .is_contract_paused()
+            .is_moderator(address)
             .returns(ReturnsResultUnmanaged)
             .run()
             .await;
@@ -415,13 +675,15 @@ impl ContractInteract {
         println!("Result: {result_value:?}");
     }
 
-    pub async fn get_total_questions(&mut self) {
+    pub async fn is_denylisted(&mut self) {
+        let address = bech32::decode("");
+
         let result_value = self
             .interactor
             .query()
             .to(self.state.current_address())
             .typed(proxy::StackTokenContractProxy)
-            .get_total_questions()
+            .is_denylisted(address)
             .returns(ReturnsResultUnmanaged)
             .run()
             .await;
@@ -429,13 +691,13 @@ impl ContractInteract {
         println!("Result: {result_value:?}");
     }
 
-    pub async fn get_total_answers(&mut self) {
+    pub async fn get_user_limits(&mut self) {
         let result_value = self
             .interactor
             .query()
             .to(self.state.current_address())
             .typed(proxy::StackTokenContractProxy)
-            .get_total_answers()
+            .get_user_limits()
             .returns(ReturnsResultUnmanaged)
             .run()
             .await;
@@ -443,13 +705,15 @@ impl ContractInteract {
         println!("Result: {result_value:?}");
     }
 
-    pub async fn get_contract_stats(&mut self) {
+    pub async fn get_open_question_count(&mut self) {
+        let address = bech32::decode("");
+
         let result_value = self
             .interactor
             .query()
             .to(self.state.current_address())
             .typed(proxy::StackTokenContractProxy)
-            .get_contract_stats()
+            .get_open_question_count(address)
             .returns(ReturnsResultUnmanaged)
             .run()
             .await;
@@ -457,4 +721,2116 @@ impl ContractInteract {
         println!("Result: {result_value:?}");
     }
 
+    pub async fn set_answer_stake(&mut self) {
+        let question_id = 0u64;
+        let amount = BigUint::<StaticApi>::from(0u128);
+
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(proxy::StackTokenContractProxy)
+            .set_answer_stake(question_id, amount)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {response:?}");
+    }
+
+    pub async fn mark_answer_as_spam(&mut self) {
+        let answer_id = 0u64;
+
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(proxy::StackTokenContractProxy)
+            .mark_answer_as_spam(answer_id)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {response:?}");
+    }
+
+    pub async fn get_answer_stake(&mut self) {
+        let question_id = 0u64;
+
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::StackTokenContractProxy)
+            .get_answer_stake(question_id)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    pub async fn get_answer_stake_deposit(&mut self) {
+        let answer_id = 0u64;
+
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::StackTokenContractProxy)
+            .get_answer_stake_deposit(answer_id)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    pub async fn is_answer_spam(&mut self) {
+        let answer_id = 0u64;
+
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::StackTokenContractProxy)
+            .is_answer_spam(answer_id)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    pub async fn get_treasury(&mut self) {
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::StackTokenContractProxy)
+            .get_treasury()
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    pub async fn commit_answer(&mut self) {
+        let egld_amount = BigUint::<StaticApi>::from(0u128);
+
+        let question_id = 0u64;
+        let commitment = ManagedByteArray::<StaticApi, 32>::new_from_bytes(&[0u8; 32]);
+
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(proxy::StackTokenContractProxy)
+            .commit_answer(question_id, commitment)
+            .egld(egld_amount)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {response:?}");
+    }
+
+    pub async fn reveal_answer(&mut self) {
+        let answer_id = 0u64;
+        let title = ManagedBuffer::new_from_bytes(&b""[..]);
+        let description = ManagedBuffer::new_from_bytes(&b""[..]);
+        let salt = ManagedBuffer::new_from_bytes(&b""[..]);
+
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(proxy::StackTokenContractProxy)
+            .reveal_answer(answer_id, title, description, salt)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {response:?}");
+    }
+
+    pub async fn enable_commit_reveal(&mut self) {
+        let question_id = 0u64;
+        let reveal_period = 0u64;
+
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(proxy::StackTokenContractProxy)
+            .enable_commit_reveal(question_id, reveal_period)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {response:?}");
+    }
+
+    pub async fn get_reveal_period(&mut self) {
+        let question_id = 0u64;
+
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::StackTokenContractProxy)
+            .get_reveal_period(question_id)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    pub async fn compute_answer_commitment(&mut self) {
+        let answerer = bech32::decode("");
+        let title = ManagedBuffer::new_from_bytes(&b""[..]);
+        let description = ManagedBuffer::new_from_bytes(&b""[..]);
+        let salt = ManagedBuffer::new_from_bytes(&b""[..]);
+
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::StackTokenContractProxy)
+            .compute_answer_commitment(answerer, title, description, salt)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    pub async fn is_answer_revealed(&mut self) {
+        let answer_id = 0u64;
+
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::StackTokenContractProxy)
+            .is_answer_revealed(answer_id)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    pub async fn compute_content_hash(&mut self) {
+        let title = ManagedBuffer::new_from_bytes(&b""[..]);
+        let description = ManagedBuffer::new_from_bytes(&b""[..]);
+
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::StackTokenContractProxy)
+            .compute_content_hash(title, description)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    pub async fn get_answer_content_hash(&mut self) {
+        let answer_id = 0u64;
+
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::StackTokenContractProxy)
+            .get_answer_content_hash(answer_id)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    pub async fn get_content_hash_first_author(&mut self) {
+        let content_hash = ManagedByteArray::<StaticApi, 32>::new_from_bytes(&[0u8; 32]);
+
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::StackTokenContractProxy)
+            .get_content_hash_first_author(content_hash)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    pub async fn claim(&mut self) {
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(proxy::StackTokenContractProxy)
+            .claim()
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {response:?}");
+    }
+
+    pub async fn set_pull_payouts(&mut self) {
+        let enabled = true;
+
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(proxy::StackTokenContractProxy)
+            .set_pull_payouts(enabled)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {response:?}");
+    }
+
+    pub async fn get_claimable(&mut self) {
+        let address = bech32::decode("");
+
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::StackTokenContractProxy)
+            .get_claimable(address)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    pub async fn is_pull_payouts_enabled(&mut self) {
+        let address = bech32::decode("");
+
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::StackTokenContractProxy)
+            .is_pull_payouts_enabled(address)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    pub async fn issue_stack_token(&mut self) {
+        let egld_amount = BigUint::<StaticApi>::from(0u128);
+
+        let token_display_name = ManagedBuffer::new_from_bytes(&b"StackToken"[..]);
+        let token_ticker = ManagedBuffer::new_from_bytes(&b"STACK"[..]);
+        let num_decimals = 18u32;
+
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(proxy::StackTokenContractProxy)
+            .issue_stack_token(token_display_name, token_ticker, num_decimals)
+            .egld(egld_amount)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {response:?}");
+    }
+
+    pub async fn set_stack_token_rewards(&mut self) {
+        let answerer_reward = BigUint::<StaticApi>::from(0u128);
+        let asker_reward = BigUint::<StaticApi>::from(0u128);
+
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(proxy::StackTokenContractProxy)
+            .set_stack_token_rewards(answerer_reward, asker_reward)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {response:?}");
+    }
+
+    pub async fn get_stack_token_id(&mut self) {
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::StackTokenContractProxy)
+            .get_stack_token_id()
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    pub async fn get_stack_token_rewards(&mut self) {
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::StackTokenContractProxy)
+            .get_stack_token_rewards()
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    pub async fn vote_answer(&mut self) {
+        let answer_id = 0u64;
+
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(proxy::StackTokenContractProxy)
+            .vote_answer(answer_id)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {response:?}");
+    }
+
+    pub async fn issue_badge_collection(&mut self) {
+        let egld_amount = BigUint::<StaticApi>::from(0u128);
+
+        let token_display_name = ManagedBuffer::new_from_bytes(&b"StackBadges"[..]);
+        let token_ticker = ManagedBuffer::new_from_bytes(&b"STACKB"[..]);
+
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(proxy::StackTokenContractProxy)
+            .issue_badge_collection(token_display_name, token_ticker)
+            .egld(egld_amount)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {response:?}");
+    }
+
+    pub async fn add_badge(&mut self) {
+        let name = ManagedBuffer::new_from_bytes(&b""[..]);
+        let criterion = proxy::BadgeCriterion::ApprovedAnswers;
+        let threshold = 0u64;
+        let uri = ManagedBuffer::new_from_bytes(&b""[..]);
+
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(proxy::StackTokenContractProxy)
+            .add_badge(name, criterion, threshold, uri)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {response:?}");
+    }
+
+    pub async fn award_badge(&mut self) {
+        let badge_id = 0u64;
+        let recipient = bech32::decode("");
+
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(proxy::StackTokenContractProxy)
+            .award_badge(badge_id, recipient)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {response:?}");
+    }
+
+    pub async fn get_badges(&mut self) {
+        let address = bech32::decode("");
+
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::StackTokenContractProxy)
+            .get_badges(address)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        let badges: Vec<Badge<StaticApi>> = result_value.into_vec();
+        println!("Found {} badges:", badges.len());
+
+        for badge in badges.iter() {
+            println!("  ID: {}", badge.badge_id);
+            println!("  Name: {}", badge.name);
+            println!("  Criterion: {:?}", badge.criterion);
+            println!("  Threshold: {}", badge.threshold);
+            println!("  URI: {}", badge.uri);
+            println!("  ---");
+        }
+    }
+
+    pub async fn get_badge_registry(&mut self) {
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::StackTokenContractProxy)
+            .get_badge_registry()
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        let badges: Vec<Badge<StaticApi>> = result_value.into_vec();
+        println!("Found {} registered badges:", badges.len());
+
+        for badge in badges.iter() {
+            println!("  ID: {}", badge.badge_id);
+            println!("  Name: {}", badge.name);
+            println!("  Criterion: {:?}", badge.criterion);
+            println!("  Threshold: {}", badge.threshold);
+            println!("  URI: {}", badge.uri);
+            println!("  ---");
+        }
+    }
+
+    pub async fn get_contribution_stats(&mut self) {
+        let address = bech32::decode("");
+
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::StackTokenContractProxy)
+            .get_contribution_stats(address)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    pub async fn stake(&mut self) {
+        let token_id = String::new();
+        let token_nonce = 0u64;
+        let token_amount = BigUint::<StaticApi>::from(0u128);
+
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(proxy::StackTokenContractProxy)
+            .stake()
+            .payment((TokenIdentifier::from(token_id.as_str()), token_nonce, token_amount))
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {response:?}");
+    }
+
+    pub async fn unstake(&mut self) {
+        let amount = BigUint::<StaticApi>::from(0u128);
+
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(proxy::StackTokenContractProxy)
+            .unstake(amount)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {response:?}");
+    }
+
+    pub async fn withdraw_unstaked(&mut self) {
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(proxy::StackTokenContractProxy)
+            .withdraw_unstaked()
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {response:?}");
+    }
+
+    pub async fn set_unbonding_period(&mut self) {
+        let unbonding_period = 0u64;
+
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(proxy::StackTokenContractProxy)
+            .set_unbonding_period(unbonding_period)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {response:?}");
+    }
+
+    pub async fn set_vote_weight_params(&mut self) {
+        let stake_per_extra_vote = BigUint::<StaticApi>::from(0u128);
+        let max_vote_weight = 1u32;
+
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(proxy::StackTokenContractProxy)
+            .set_vote_weight_params(stake_per_extra_vote, max_vote_weight)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {response:?}");
+    }
+
+    pub async fn get_vote_weight(&mut self) {
+        let address = bech32::decode("");
+
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::StackTokenContractProxy)
+            .get_vote_weight(address)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    pub async fn get_platform_fee_for(&mut self) {
+        let recipient = bech32::decode("");
+        let amount = BigUint::<StaticApi>::from(0u128);
+
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::StackTokenContractProxy)
+            .get_platform_fee_for(recipient, amount)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    pub async fn get_staked_amount(&mut self) {
+        let address = bech32::decode("");
+
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::StackTokenContractProxy)
+            .get_staked_amount(address)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    pub async fn get_unbonding(&mut self) {
+        let address = bech32::decode("");
+
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::StackTokenContractProxy)
+            .get_unbonding(address)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    pub async fn get_total_staked(&mut self) {
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::StackTokenContractProxy)
+            .get_total_staked()
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    pub async fn get_platform_fee(&mut self) {
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::StackTokenContractProxy)
+            .get_platform_fee()
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    pub async fn propose_parameter_change(&mut self) {
+        let parameter = proxy::GovernanceParameter::PlatformFee;
+        let value = BigUint::<StaticApi>::from(0u128);
+
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(proxy::StackTokenContractProxy)
+            .propose_parameter_change(parameter, value)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {response:?}");
+    }
+
+    pub async fn vote_proposal(&mut self) {
+        let proposal_id = 0u64;
+        let support = true;
+
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(proxy::StackTokenContractProxy)
+            .vote_proposal(proposal_id, support)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {response:?}");
+    }
+
+    pub async fn execute_proposal(&mut self) {
+        let proposal_id = 0u64;
+
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(proxy::StackTokenContractProxy)
+            .execute_proposal(proposal_id)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {response:?}");
+    }
+
+    pub async fn set_governance_params(&mut self) {
+        let voting_period = 0u64;
+        let timelock = 0u64;
+        let quorum = BigUint::<StaticApi>::from(0u128);
+        let proposal_min_stake = BigUint::<StaticApi>::from(0u128);
+        let proposal_min_reputation = 0u64;
+
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(proxy::StackTokenContractProxy)
+            .set_governance_params(voting_period, timelock, quorum, proposal_min_stake, proposal_min_reputation)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {response:?}");
+    }
+
+    pub async fn get_min_bounty(&mut self) {
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::StackTokenContractProxy)
+            .get_min_bounty()
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    pub async fn get_deadline_bounds(&mut self) {
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::StackTokenContractProxy)
+            .get_deadline_bounds()
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    pub async fn get_proposal(&mut self) {
+        let proposal_id = 0u64;
+
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::StackTokenContractProxy)
+            .get_proposal(proposal_id)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        match result_value.into_option() {
+            Some(proposal) => print_proposal(&proposal),
+            None => println!("Proposal with ID {} not found", proposal_id),
+        }
+    }
+
+    pub async fn get_proposal_status(&mut self) {
+        let proposal_id = 0u64;
+
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::StackTokenContractProxy)
+            .get_proposal_status(proposal_id)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    pub async fn get_active_proposals(&mut self) {
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::StackTokenContractProxy)
+            .get_active_proposals()
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        let proposals: Vec<Proposal<StaticApi>> = result_value.into_vec();
+        println!("Found {} active proposals:", proposals.len());
+
+        for proposal in proposals.iter() {
+            print_proposal(proposal);
+            println!("  ---");
+        }
+    }
+
+    pub async fn get_proposal_votes(&mut self) {
+        let proposal_id = 0u64;
+
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::StackTokenContractProxy)
+            .get_proposal_votes(proposal_id)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    pub async fn queue_admin_action(&mut self) {
+        let action = proxy::AdminAction::<StaticApi>::SetTimelockDelay(0u64);
+
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(proxy::StackTokenContractProxy)
+            .queue_admin_action(action)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {response:?}");
+    }
+
+    pub async fn execute_admin_action(&mut self) {
+        let action_id = 0u64;
+
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(proxy::StackTokenContractProxy)
+            .execute_admin_action(action_id)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {response:?}");
+    }
+
+    pub async fn cancel_admin_action(&mut self) {
+        let action_id = 0u64;
+
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(proxy::StackTokenContractProxy)
+            .cancel_admin_action(action_id)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {response:?}");
+    }
+
+    pub async fn get_pending_admin_actions(&mut self) {
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::StackTokenContractProxy)
+            .get_pending_admin_actions()
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        let actions: Vec<QueuedAdminAction<StaticApi>> = result_value.into_vec();
+        println!("Found {} pending admin actions:", actions.len());
+
+        for queued in actions.iter() {
+            println!("  Action ID: {}", queued.action_id);
+            println!("  Action: {:?}", queued.action);
+            println!("  Queued At: {}", queued.queued_at);
+            println!("  Executable At: {}", queued.executable_at);
+            println!("  ---");
+        }
+    }
+
+    pub async fn get_admin_timelock_delay(&mut self) {
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::StackTokenContractProxy)
+            .get_admin_timelock_delay()
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    pub async fn emergency_refund_batch(&mut self) {
+        let max_items = 50u64;
+
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(proxy::StackTokenContractProxy)
+            .emergency_refund_batch(max_items)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {response:?}");
+    }
+
+    pub async fn get_emergency_refund_progress(&mut self) {
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::StackTokenContractProxy)
+            .get_emergency_refund_progress()
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    pub async fn get_token_totals(&mut self) {
+        let token = EgldOrEsdtTokenIdentifier::egld();
+
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::StackTokenContractProxy)
+            .get_token_totals(token)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    pub async fn check_solvency(&mut self) {
+        let token = EgldOrEsdtTokenIdentifier::egld();
+
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::StackTokenContractProxy)
+            .check_solvency(token)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    pub async fn tip_answer(&mut self) {
+        let egld_amount = BigUint::<StaticApi>::from(0u128);
+
+        let answer_id = 0u64;
+
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(proxy::StackTokenContractProxy)
+            .tip_answer(answer_id)
+            .egld(egld_amount)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {response:?}");
+    }
+
+    pub async fn get_answer_tips(&mut self) {
+        let answer_id = 0u64;
+
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::StackTokenContractProxy)
+            .get_answer_tips(answer_id)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    pub async fn create_sponsor_pool(&mut self) {
+        let egld_amount = BigUint::<StaticApi>::from(0u128);
+
+        let tag = ManagedBuffer::new_from_bytes(&b""[..]);
+        let match_amount = BigUint::<StaticApi>::from(0u128);
+        let match_ratio_bps = 0u64;
+
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(proxy::StackTokenContractProxy)
+            .create_sponsor_pool(tag, match_amount, match_ratio_bps)
+            .egld(egld_amount)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {response:?}");
+    }
+
+    pub async fn fund_sponsor_pool(&mut self) {
+        let egld_amount = BigUint::<StaticApi>::from(0u128);
+
+        let pool_id = 0u64;
+
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(proxy::StackTokenContractProxy)
+            .fund_sponsor_pool(pool_id)
+            .egld(egld_amount)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {response:?}");
+    }
+
+    pub async fn withdraw_sponsor_pool(&mut self) {
+        let pool_id = 0u64;
+
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(proxy::StackTokenContractProxy)
+            .withdraw_sponsor_pool(pool_id)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {response:?}");
+    }
+
+    pub async fn get_sponsor_pool(&mut self) {
+        let pool_id = 0u64;
+
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::StackTokenContractProxy)
+            .get_sponsor_pool(pool_id)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    pub async fn get_pools_by_tag(&mut self) {
+        let tag = ManagedBuffer::new_from_bytes(&b""[..]);
+
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::StackTokenContractProxy)
+            .get_pools_by_tag(tag)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    pub async fn get_pool_matches(&mut self) {
+        let pool_id = 0u64;
+
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::StackTokenContractProxy)
+            .get_pool_matches(pool_id)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    pub async fn get_question_tags(&mut self) {
+        let question_id = 0u64;
+
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::StackTokenContractProxy)
+            .get_question_tags(question_id)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    pub async fn post_milestone_question(&mut self) {
+        let egld_amount = BigUint::<StaticApi>::from(0u128);
+
+        let title = ManagedBuffer::new_from_bytes(&b""[..]);
+        let description = ManagedBuffer::new_from_bytes(&b""[..]);
+        let deadline = 0u64;
+        let milestones = MultiValueVec::<MultiValue2<ManagedBuffer<StaticApi>, BigUint<StaticApi>>>::new();
+
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(proxy::StackTokenContractProxy)
+            .post_milestone_question(title, description, deadline, milestones)
+            .egld(egld_amount)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {response:?}");
+    }
+
+    pub async fn approve_milestone(&mut self) {
+        let question_id = 0u64;
+        let milestone_id = 0usize;
+        let answer_id = 0u64;
+
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(proxy::StackTokenContractProxy)
+            .approve_milestone(question_id, milestone_id, answer_id)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {response:?}");
+    }
+
+    pub async fn get_milestones(&mut self) {
+        let question_id = 0u64;
+
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::StackTokenContractProxy)
+            .get_milestones(question_id)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    pub async fn assign_question(&mut self) {
+        let question_id = 0u64;
+        let assignee = bech32::decode("");
+        let review_window = 0u64;
+
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(proxy::StackTokenContractProxy)
+            .assign_question(question_id, assignee, review_window)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {response:?}");
+    }
+
+    pub async fn accept_assignment(&mut self) {
+        let question_id = 0u64;
+
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(proxy::StackTokenContractProxy)
+            .accept_assignment(question_id)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {response:?}");
+    }
+
+    pub async fn mark_delivered(&mut self) {
+        let question_id = 0u64;
+        let title = ManagedBuffer::new_from_bytes(&b""[..]);
+        let description = ManagedBuffer::new_from_bytes(&b""[..]);
+
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(proxy::StackTokenContractProxy)
+            .mark_delivered(question_id, title, description)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {response:?}");
+    }
+
+    pub async fn dispute_delivery(&mut self) {
+        let question_id = 0u64;
+
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(proxy::StackTokenContractProxy)
+            .dispute_delivery(question_id)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {response:?}");
+    }
+
+    pub async fn release_delivery(&mut self) {
+        let question_id = 0u64;
+
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(proxy::StackTokenContractProxy)
+            .release_delivery(question_id)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {response:?}");
+    }
+
+    pub async fn resolve_dispute(&mut self) {
+        let question_id = 0u64;
+        let release_to_assignee = true;
+
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(proxy::StackTokenContractProxy)
+            .resolve_dispute(question_id, release_to_assignee)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {response:?}");
+    }
+
+    pub async fn get_job_assignment(&mut self) {
+        let question_id = 0u64;
+
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::StackTokenContractProxy)
+            .get_job_assignment(question_id)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    pub async fn set_reviewers(&mut self) {
+        let question_id = 0u64;
+        let required_approvals = 1u32;
+        let reviewers = MultiValueVec::<ManagedAddress<StaticApi>>::new();
+
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(proxy::StackTokenContractProxy)
+            .set_reviewers(question_id, required_approvals, reviewers)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {response:?}");
+    }
+
+    pub async fn get_reviewers(&mut self) {
+        let question_id = 0u64;
+
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::StackTokenContractProxy)
+            .get_reviewers(question_id)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    pub async fn get_reviewer_approvals(&mut self) {
+        let answer_id = 0u64;
+
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::StackTokenContractProxy)
+            .get_reviewer_approvals(answer_id)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    pub async fn post_invite_only_question(&mut self) {
+        let egld_amount = BigUint::<StaticApi>::from(0u128);
+
+        let title = ManagedBuffer::new_from_bytes(&b""[..]);
+        let description = ManagedBuffer::new_from_bytes(&b""[..]);
+        let deadline = 0u64;
+        let answerers = MultiValueVec::<ManagedAddress<StaticApi>>::new();
+
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(proxy::StackTokenContractProxy)
+            .post_invite_only_question(title, description, deadline, answerers)
+            .egld(egld_amount)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {response:?}");
+    }
+
+    pub async fn add_allowed_answerers(&mut self) {
+        let question_id = 0u64;
+        let answerers = MultiValueVec::<ManagedAddress<StaticApi>>::new();
+
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(proxy::StackTokenContractProxy)
+            .add_allowed_answerers(question_id, answerers)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {response:?}");
+    }
+
+    pub async fn remove_allowed_answerers(&mut self) {
+        let question_id = 0u64;
+        let answerers = MultiValueVec::<ManagedAddress<StaticApi>>::new();
+
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(proxy::StackTokenContractProxy)
+            .remove_allowed_answerers(question_id, answerers)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {response:?}");
+    }
+
+    pub async fn can_answer(&mut self) {
+        let question_id = 0u64;
+        let address = bech32::decode("");
+
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::StackTokenContractProxy)
+            .can_answer(question_id, address)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    pub async fn get_allowed_answerers(&mut self) {
+        let question_id = 0u64;
+
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::StackTokenContractProxy)
+            .get_allowed_answerers(question_id)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    pub async fn post_puzzle_question(&mut self) {
+        let egld_amount = BigUint::<StaticApi>::from(0u128);
+
+        let title = ManagedBuffer::new_from_bytes(&b""[..]);
+        let description = ManagedBuffer::new_from_bytes(&b""[..]);
+        let deadline = 0u64;
+        let answer_hash = ManagedByteArray::<StaticApi, 32>::new_from_bytes(&[0u8; 32]);
+
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(proxy::StackTokenContractProxy)
+            .post_puzzle_question(title, description, deadline, answer_hash)
+            .egld(egld_amount)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {response:?}");
+    }
+
+    pub async fn commit_puzzle_solution(&mut self) {
+        let question_id = 0u64;
+        let commitment = ManagedByteArray::<StaticApi, 32>::new_from_bytes(&[0u8; 32]);
+
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(proxy::StackTokenContractProxy)
+            .commit_puzzle_solution(question_id, commitment)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {response:?}");
+    }
+
+    pub async fn solve_puzzle(&mut self) {
+        let question_id = 0u64;
+        let preimage = ManagedBuffer::new_from_bytes(&b""[..]);
+
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(proxy::StackTokenContractProxy)
+            .solve_puzzle(question_id, preimage)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {response:?}");
+    }
+
+    pub async fn compute_puzzle_commitment(&mut self) {
+        let solver = bech32::decode("");
+        let preimage = ManagedBuffer::new_from_bytes(&b""[..]);
+
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::StackTokenContractProxy)
+            .compute_puzzle_commitment(solver, preimage)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    pub async fn get_puzzle_hash(&mut self) {
+        let question_id = 0u64;
+
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::StackTokenContractProxy)
+            .get_puzzle_hash(question_id)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    pub async fn post_competition_question(&mut self) {
+        let egld_amount = BigUint::<StaticApi>::from(0u128);
+
+        let title = ManagedBuffer::new_from_bytes(&b""[..]);
+        let description = ManagedBuffer::new_from_bytes(&b""[..]);
+        let deadline = 0u64;
+        let payout_schedule_bps = MultiValueVec::from(vec![6_000u64, 3_000u64, 1_000u64]);
+
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(proxy::StackTokenContractProxy)
+            .post_competition_question(title, description, deadline, payout_schedule_bps)
+            .egld(egld_amount)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {response:?}");
+    }
+
+    pub async fn rank_answers(&mut self) {
+        let question_id = 0u64;
+        let ordered_ids = MultiValueVec::<u64>::new();
+
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(proxy::StackTokenContractProxy)
+            .rank_answers(question_id, ordered_ids)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {response:?}");
+    }
+
+    pub async fn get_payout_schedule(&mut self) {
+        let question_id = 0u64;
+
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::StackTokenContractProxy)
+            .get_payout_schedule(question_id)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    pub async fn post_decaying_question(&mut self) {
+        let egld_amount = BigUint::<StaticApi>::from(0u128);
+
+        let title = ManagedBuffer::new_from_bytes(&b""[..]);
+        let description = ManagedBuffer::new_from_bytes(&b""[..]);
+        let deadline = 0u64;
+        let decay = proxy::DecayCurve::Linear { duration: 0, min_payout_bps: 0 };
+
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(proxy::StackTokenContractProxy)
+            .post_decaying_question(title, description, deadline, decay)
+            .egld(egld_amount)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {response:?}");
+    }
+
+    pub async fn get_current_payout(&mut self) {
+        let question_id = 0u64;
+
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::StackTokenContractProxy)
+            .get_current_payout(question_id)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    pub async fn get_bounty_decay(&mut self) {
+        let question_id = 0u64;
+
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::StackTokenContractProxy)
+            .get_bounty_decay(question_id)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    pub async fn set_vesting_period(&mut self) {
+        let question_id = 0u64;
+        let period = 0u64;
+
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(proxy::StackTokenContractProxy)
+            .set_vesting_period(question_id, period)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {response:?}");
+    }
+
+    pub async fn claim_vested(&mut self) {
+        let question_id = 0u64;
+
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(proxy::StackTokenContractProxy)
+            .claim_vested(question_id)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {response:?}");
+    }
+
+    pub async fn dispute_vesting(&mut self) {
+        let question_id = 0u64;
+
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(proxy::StackTokenContractProxy)
+            .dispute_vesting(question_id)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {response:?}");
+    }
+
+    pub async fn resolve_vesting_dispute(&mut self) {
+        let question_id = 0u64;
+        let release_to_answerer = true;
+
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(proxy::StackTokenContractProxy)
+            .resolve_vesting_dispute(question_id, release_to_answerer)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {response:?}");
+    }
+
+    pub async fn get_vesting(&mut self) {
+        let question_id = 0u64;
+
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::StackTokenContractProxy)
+            .get_vesting(question_id)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    pub async fn get_vested_claimable(&mut self) {
+        let question_id = 0u64;
+
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::StackTokenContractProxy)
+            .get_vested_claimable(question_id)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    pub async fn get_vesting_period(&mut self) {
+        let question_id = 0u64;
+
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::StackTokenContractProxy)
+            .get_vesting_period(question_id)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    pub async fn add_comment(&mut self) {
+        let target = proxy::CommentTarget::Question(0u64);
+        let content = ManagedBuffer::new_from_bytes(&b""[..]);
+        let reply_to = OptionalValue::<u64>::None;
+
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(proxy::StackTokenContractProxy)
+            .add_comment(target, content, reply_to)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {response:?}");
+    }
+
+    pub async fn set_comment_hidden(&mut self) {
+        let comment_id = 0u64;
+        let hidden = true;
+
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(proxy::StackTokenContractProxy)
+            .set_comment_hidden(comment_id, hidden)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {response:?}");
+    }
+
+    pub async fn get_comments(&mut self) {
+        let target = proxy::CommentTarget::Question(0u64);
+        let offset = 0usize;
+        let limit = 20usize;
+
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::StackTokenContractProxy)
+            .get_comments(target, offset, limit)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    pub async fn get_comment_count(&mut self) {
+        let target = proxy::CommentTarget::Question(0u64);
+
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::StackTokenContractProxy)
+            .get_comment_count(target)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    pub async fn close_as_duplicate(&mut self) {
+        let question_id = 0u64;
+        let canonical_id = 0u64;
+        let move_bounty = false;
+
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(proxy::StackTokenContractProxy)
+            .close_as_duplicate(question_id, canonical_id, move_bounty)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {response:?}");
+    }
+
+    pub async fn set_profile(&mut self) {
+        let display_name = ManagedBuffer::new_from_bytes(&b""[..]);
+        let bio_uri = ManagedBuffer::new_from_bytes(&b""[..]);
+        let skills = MultiValueVec::<ManagedBuffer<StaticApi>>::new();
+
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(proxy::StackTokenContractProxy)
+            .set_profile(display_name, bio_uri, skills)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {response:?}");
+    }
+
+    pub async fn get_profile(&mut self) {
+        let address = bech32::decode("");
+
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::StackTokenContractProxy)
+            .get_profile(address)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    pub async fn get_users_by_skill(&mut self) {
+        let skill = ManagedBuffer::new_from_bytes(&b""[..]);
+
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::StackTokenContractProxy)
+            .get_users_by_skill(skill)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    pub async fn get_address_by_display_name(&mut self) {
+        let display_name = ManagedBuffer::new_from_bytes(&b""[..]);
+
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::StackTokenContractProxy)
+            .get_address_by_display_name(display_name)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    pub async fn get_stake_locked_until(&mut self) {
+        let address = bech32::decode("");
+
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::StackTokenContractProxy)
+            .get_stake_locked_until(address)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    pub async fn claim_badges(&mut self) {
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(proxy::StackTokenContractProxy)
+            .claim_badges()
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {response:?}");
+    }
+
+    pub async fn get_pending_badges(&mut self) {
+        let address = bech32::decode("");
+
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::StackTokenContractProxy)
+            .get_pending_badges(address)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+}
+
+fn print_proposal(proposal: &Proposal<StaticApi>) {
+    println!("Proposal {}:", proposal.proposal_id);
+    println!("  Proposer: {}", bech32::encode(&proposal.proposer.to_address()));
+    println!("  Parameter: {:?}", proposal.parameter);
+    println!("  Value: {:?}", proposal.value);
+    println!("  Created At: {}", proposal.created_at);
+    println!("  Voting Ends At: {}", proposal.voting_ends_at);
+    println!("  Votes For: {:?}", proposal.votes_for);
+    println!("  Votes Against: {:?}", proposal.votes_against);
+    println!("  Executed: {}", proposal.executed);
 }
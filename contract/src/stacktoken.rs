@@ -5,6 +5,32 @@ multiversx_sc::imports!();
 multiversx_sc::derive_imports!();
 
 pub const MIN_EGLD_LOCKED: u64 = 100_000_000_000_000_000; // 0.1 EGLD in wei
+const NORMALIZE_CHUNK_SIZE: usize = 256;
+pub const BPS_DENOMINATOR: u64 = 10_000;
+pub const MAX_PLATFORM_FEE_BPS: u64 = 1_000; // 10%
+pub const MAX_TAGS_PER_QUESTION: usize = 5;
+pub const MAX_TAG_LENGTH: usize = 32;
+pub const MIN_SPONSOR_POOL_DEPOSIT: u64 = MIN_EGLD_LOCKED;
+pub const MAX_POOLS_PER_TAG: usize = 10;
+// Every pool drains within a bounded number of matches, so a full tag frees up again
+pub const MIN_SPONSOR_MATCH_AMOUNT: u64 = MIN_SPONSOR_POOL_DEPOSIT / 10;
+pub const MIN_SPONSOR_MATCH_BPS: u64 = 1_000; // 10%
+pub const MAX_MILESTONES: usize = 10;
+pub const MAX_REVEAL_PERIOD: u64 = 30 * 24 * 60 * 60; // 30 days
+pub const MAX_REVIEW_WINDOW: u64 = 30 * 24 * 60 * 60; // 30 days
+pub const MAX_VESTING_PERIOD: u64 = 365 * 24 * 60 * 60; // 1 year
+pub const MIN_ADMIN_TIMELOCK_DELAY: u64 = 24 * 60 * 60; // 1 day
+pub const MAX_ADMIN_TIMELOCK_DELAY: u64 = 30 * 24 * 60 * 60; // 30 days
+pub const MIN_VOTING_PERIOD: u64 = 24 * 60 * 60; // 1 day
+pub const MIN_GOVERNANCE_TIMELOCK: u64 = MIN_ADMIN_TIMELOCK_DELAY;
+pub const MIN_UNBONDING_PERIOD: u64 = 24 * 60 * 60; // 1 day
+pub const MAX_REVIEWERS: usize = 10;
+pub const MAX_COMPETITION_WINNERS: usize = 10;
+pub const MAX_COMMENT_LENGTH: usize = 500;
+pub const MAX_COMMENTS_PAGE_SIZE: usize = 50;
+pub const MAX_DISPLAY_NAME_LENGTH: usize = 32;
+pub const MAX_BIO_URI_LENGTH: usize = 256;
+pub const MAX_SKILLS_PER_PROFILE: usize = 10;
 pub mod stacktoken_proxy;
 
 #[type_abi]
@@ -14,6 +40,12 @@ pub enum QuestionStatus {
     Answered,
     AnswerApproved,
     Expired,
+    EmergencyRefunded,
+    Assigned,
+    InProgress,
+    Delivered,
+    Disputed,
+    Duplicate,
 }
 
 #[type_abi]
@@ -28,6 +60,7 @@ pub struct Question<M: ManagedTypeApi> {
     pub created_at: u64,
     pub status: QuestionStatus,
     pub approved_answer_id: Option<u64>,
+    pub duplicate_of: Option<u64>,
 }
 
 #[type_abi]
@@ -50,6 +83,185 @@ pub struct QuestionCreatedEventData<M: ManagedTypeApi> {
     pub locked_amount: BigUint<M>,
 }
 
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Debug)]
+pub enum BadgeCriterion {
+    ApprovedAnswers,
+    VotesReceived,
+    VotesCast,
+    Manual,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct Badge<M: ManagedTypeApi> {
+    pub badge_id: u64,
+    pub name: ManagedBuffer<M>,
+    pub criterion: BadgeCriterion,
+    pub threshold: u64,
+    pub uri: ManagedBuffer<M>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Debug)]
+pub enum GovernanceParameter {
+    MinBounty,
+    PlatformFee,
+    MinDeadlineDuration,
+    MaxDeadlineDuration,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Debug)]
+pub enum ProposalStatus {
+    Voting,
+    Defeated,
+    Timelocked,
+    Executable,
+    Executed,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct Proposal<M: ManagedTypeApi> {
+    pub proposal_id: u64,
+    pub proposer: ManagedAddress<M>,
+    pub parameter: GovernanceParameter,
+    pub value: BigUint<M>,
+    pub created_at: u64,
+    pub voting_ends_at: u64,
+    pub votes_for: BigUint<M>,
+    pub votes_against: BigUint<M>,
+    pub executed: bool,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Debug)]
+pub enum AdminAction<M: ManagedTypeApi> {
+    // Only changes the address reported by getOwner; owner-only endpoints follow the
+    // protocol-level owner, which has to be changed with a ChangeOwnerAddress transaction
+    TransferOwnership(ManagedAddress<M>),
+    SetPlatformFee(u64),
+    SetFeeDiscount(BigUint<M>, u64),
+    SetTreasury(ManagedAddress<M>),
+    AddModerator(ManagedAddress<M>),
+    RemoveModerator(ManagedAddress<M>),
+    AddToDenylist(ManagedAddress<M>),
+    RemoveFromDenylist(ManagedAddress<M>),
+    SetTimelockDelay(u64),
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct QueuedAdminAction<M: ManagedTypeApi> {
+    pub action_id: u64,
+    pub action: AdminAction<M>,
+    pub queued_at: u64,
+    pub executable_at: u64,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Debug)]
+pub struct SponsorPool<M: ManagedTypeApi> {
+    pub pool_id: u64,
+    pub sponsor: ManagedAddress<M>,
+    pub tag: ManagedBuffer<M>,
+    pub balance: BigUint<M>,
+    pub match_amount: BigUint<M>,
+    pub match_ratio_bps: u64,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Debug)]
+pub struct SponsorMatch<M: ManagedTypeApi> {
+    pub pool_id: u64,
+    pub question_id: u64,
+    pub amount: BigUint<M>,
+    pub timestamp: u64,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Debug)]
+pub enum MilestoneStatus {
+    Pending,
+    Approved,
+    Refunded,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Debug)]
+pub struct Milestone<M: ManagedTypeApi> {
+    pub milestone_id: usize,
+    pub name: ManagedBuffer<M>,
+    pub amount: BigUint<M>,
+    pub status: MilestoneStatus,
+    pub answer_id: Option<u64>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Debug)]
+pub struct JobAssignment<M: ManagedTypeApi> {
+    pub assignee: ManagedAddress<M>,
+    pub review_window: u64,
+    pub accepted_at: u64,
+    pub delivered_at: u64,
+    pub answer_id: Option<u64>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Debug)]
+pub enum DecayCurve {
+    Linear {
+        duration: u64,
+        min_payout_bps: u64,
+    },
+    Stepwise {
+        step_duration: u64,
+        step_bps: u64,
+        min_payout_bps: u64,
+    },
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Debug)]
+pub struct Vesting<M: ManagedTypeApi> {
+    pub beneficiary: ManagedAddress<M>,
+    pub total_amount: BigUint<M>,
+    pub claimed_amount: BigUint<M>,
+    pub start: u64,
+    pub end: u64,
+    pub frozen_at: u64,
+    pub dispute_resolved: bool,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Debug)]
+pub enum CommentTarget {
+    Question(u64),
+    Answer(u64),
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Debug)]
+pub struct Comment<M: ManagedTypeApi> {
+    pub comment_id: u64,
+    pub author: ManagedAddress<M>,
+    pub target: CommentTarget,
+    pub reply_to: Option<u64>,
+    pub content: ManagedBuffer<M>,
+    pub created_at: u64,
+    pub hidden: bool,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Debug)]
+pub struct Profile<M: ManagedTypeApi> {
+    pub display_name: ManagedBuffer<M>,
+    pub bio_uri: ManagedBuffer<M>,
+    pub skills: ManagedVec<M, ManagedBuffer<M>>,
+    pub updated_at: u64,
+}
+
 #[multiversx_sc::contract]
 pub trait StackTokenContract {
     #[init]
@@ -57,6 +269,8 @@ pub trait StackTokenContract {
         let caller = self.blockchain().get_caller();
         self.owner().set(&caller);
         self.is_paused().set(false);
+        self.admin_timelock_delay().set(MIN_ADMIN_TIMELOCK_DELAY);
+        self.unbonding_period().set(MIN_UNBONDING_PERIOD);
     }
 
     #[payable("EGLD")]
@@ -66,21 +280,159 @@ pub trait StackTokenContract {
         title: ManagedBuffer,
         description: ManagedBuffer,
         deadline: u64,
+        tags: MultiValueEncoded<ManagedBuffer>,
     ) {
+        self.create_question(title, description, deadline, tags);
+    }
+
+    // Milestone bounties: the payment is split into named parts that are approved one by one
+    #[payable("EGLD")]
+    #[endpoint(postMilestoneQuestion)]
+    fn post_milestone_question(
+        &self,
+        title: ManagedBuffer,
+        description: ManagedBuffer,
+        deadline: u64,
+        milestones: MultiValueEncoded<MultiValue2<ManagedBuffer, BigUint>>,
+    ) -> u64 {
+        require!(!milestones.is_empty(), "No milestones");
+        require!(milestones.len() <= MAX_MILESTONES, "Too many milestones");
+
+        let qid = self.create_question(title, description, deadline, MultiValueEncoded::new());
+        let mut total = BigUint::zero();
+        for (index, milestone) in milestones.into_iter().enumerate() {
+            let (name, amount) = milestone.into_tuple();
+            require!(!name.is_empty(), "Milestone name is empty");
+            require!(amount > 0, "Milestone amount must be positive");
+            total += &amount;
+            self.question_milestones(&qid).push(&Milestone {
+                milestone_id: index + 1,
+                name,
+                amount,
+                status: MilestoneStatus::Pending,
+                answer_id: None,
+            });
+        }
+        require!(total == *self.call_value().egld(), "Milestones must add up to the payment");
+        qid
+    }
+
+    // Invite-only questions: only addresses on the question's allowlist may answer
+    #[payable("EGLD")]
+    #[endpoint(postInviteOnlyQuestion)]
+    fn post_invite_only_question(
+        &self,
+        title: ManagedBuffer,
+        description: ManagedBuffer,
+        deadline: u64,
+        answerers: MultiValueEncoded<ManagedAddress>,
+    ) -> u64 {
+        let qid = self.create_question(title, description, deadline, MultiValueEncoded::new());
+        self.invite_only(&qid).set(true);
+        self.add_allowed_answerers(qid, answerers);
+        qid
+    }
+
+    // Puzzle bounties: the creator posts sha256 of the expected answer and the first solver to
+    // reveal a matching preimage is paid without a manual approval
+    #[payable("EGLD")]
+    #[endpoint(postPuzzleQuestion)]
+    fn post_puzzle_question(
+        &self,
+        title: ManagedBuffer,
+        description: ManagedBuffer,
+        deadline: u64,
+        answer_hash: ManagedByteArray<32>,
+    ) -> u64 {
+        let qid = self.create_question(title, description, deadline, MultiValueEncoded::new());
+        self.puzzle_hash(&qid).set(&answer_hash);
+        qid
+    }
+
+    // Competition questions: the bounty is split between ranked winners following a schedule in
+    // basis points, e.g. 6000/3000/1000
+    #[payable("EGLD")]
+    #[endpoint(postCompetitionQuestion)]
+    fn post_competition_question(
+        &self,
+        title: ManagedBuffer,
+        description: ManagedBuffer,
+        deadline: u64,
+        payout_schedule_bps: MultiValueEncoded<u64>,
+    ) -> u64 {
+        require!(!payout_schedule_bps.is_empty(), "Empty payout schedule");
+        require!(payout_schedule_bps.len() <= MAX_COMPETITION_WINNERS, "Too many winners");
+
+        let qid = self.create_question(title, description, deadline, MultiValueEncoded::new());
+        let mut total_bps = 0u64;
+        for share_bps in payout_schedule_bps {
+            require!(share_bps > 0, "Payout share must be positive");
+            total_bps += share_bps;
+            self.competition_schedule(&qid).push(&share_bps);
+        }
+        require!(total_bps == BPS_DENOMINATOR, "Payout schedule must add up to 100%");
+        qid
+    }
+
+    // Decaying bounties: the approved answer earns less the later it was submitted after the
+    // question was posted; the remainder goes back to the creator
+    #[payable("EGLD")]
+    #[endpoint(postDecayingQuestion)]
+    fn post_decaying_question(
+        &self,
+        title: ManagedBuffer,
+        description: ManagedBuffer,
+        deadline: u64,
+        decay: DecayCurve,
+    ) -> u64 {
+        match &decay {
+            DecayCurve::Linear { duration, min_payout_bps } => {
+                require!(*duration > 0, "Decay duration must be positive");
+                require!(*min_payout_bps <= BPS_DENOMINATOR, "Minimum payout too high");
+            },
+            DecayCurve::Stepwise { step_duration, step_bps, min_payout_bps } => {
+                require!(*step_duration > 0, "Decay duration must be positive");
+                require!(*step_bps > 0 && *step_bps <= BPS_DENOMINATOR, "Invalid decay step");
+                require!(*min_payout_bps <= BPS_DENOMINATOR, "Minimum payout too high");
+            },
+        }
+
+        let qid = self.create_question(title, description, deadline, MultiValueEncoded::new());
+        self.bounty_decay(&qid).set(&decay);
+        qid
+    }
+
+    fn create_question(
+        &self,
+        title: ManagedBuffer,
+        description: ManagedBuffer,
+        deadline: u64,
+        tags: MultiValueEncoded<ManagedBuffer>,
+    ) -> u64 {
         require!(!self.is_paused().get(), "Contract is paused");
-        
+
         let caller = self.blockchain().get_caller();
+        self.require_not_denylisted(&caller);
+
         let payment = self.call_value().egld();
-        require!(*payment >= BigUint::from(MIN_EGLD_LOCKED), "Insufficient EGLD");
+        require!(*payment >= self.get_min_bounty(), "Insufficient EGLD");
         require!(!title.is_empty() && !description.is_empty(), "Title or description is empty");
         require!(deadline > self.blockchain().get_block_timestamp(), "Invalid deadline");
+        self.require_deadline_within_bounds(deadline);
+
+        let max_open_questions = self.max_open_questions_per_user().get();
+        require!(
+            max_open_questions == 0 || self.open_question_count(&caller).get() < max_open_questions,
+            "Too many open questions"
+        );
+        self.apply_post_cooldown(&caller);
 
         let qid = self.question_id().update(|id| {
             *id += 1;
             *id
         });
 
-        let question = Question {
+        let mut question = Question {
             question_id: qid,
             creator: caller.clone(),
             title,
@@ -90,21 +442,29 @@ pub trait StackTokenContract {
             created_at: self.blockchain().get_block_timestamp(),
             status: QuestionStatus::Created,
             approved_answer_id: None,
+            duplicate_of: None,
         };
+        self.set_question_tags(qid, tags);
+        question.locked_amount += self.apply_sponsor_matches(qid, &payment);
 
         self.questions(&qid).set(&question);
         self.user_questions(&caller).insert(qid);
+        self.open_question_count(&caller).update(|count| *count += 1);
+        self.total_locked(&EgldOrEsdtTokenIdentifier::egld())
+            .update(|total| *total += &question.locked_amount);
 
         self.event_question_created(
             &qid,
             &caller,
             &QuestionCreatedEventData {
                 deadline,
-                locked_amount: payment.clone(),
+                locked_amount: question.locked_amount.clone(),
             },
         );
+        qid
     }
 
+    #[payable("EGLD")]
     #[endpoint(submitAnswer)]
     fn submit_answer(
         &self,
@@ -112,6 +472,70 @@ pub trait StackTokenContract {
         title: ManagedBuffer,
         description: ManagedBuffer,
     ) {
+        require!(self.reveal_period(&question_id).get() == 0, "Question requires commit-reveal answers");
+        self.create_answer(question_id, title, description);
+    }
+
+    // Commit-reveal answering: the answer is stored empty with its commitment and revealed after the deadline
+    #[payable("EGLD")]
+    #[endpoint(commitAnswer)]
+    fn commit_answer(&self, question_id: u64, commitment: ManagedByteArray<32>) {
+        require!(self.reveal_period(&question_id).get() > 0, "Question does not use commit-reveal");
+        let aid = self.create_answer(question_id, ManagedBuffer::new(), ManagedBuffer::new());
+        self.answer_commitment(&aid).set(&commitment);
+    }
+
+    #[endpoint(revealAnswer)]
+    fn reveal_answer(
+        &self,
+        answer_id: u64,
+        title: ManagedBuffer,
+        description: ManagedBuffer,
+        salt: ManagedBuffer,
+    ) {
+        let caller = self.blockchain().get_caller();
+        let timestamp = self.blockchain().get_block_timestamp();
+        let mut answer = self.answers(&answer_id).get();
+        let question = self.questions(&answer.question_id).get();
+
+        require!(caller == answer.creator, "Only answer creator can reveal");
+        require!(!self.answer_commitment(&answer_id).is_empty(), "Nothing to reveal");
+        require!(timestamp >= question.deadline, "Reveal window not open");
+        require!(timestamp < self.closing_time(&question), "Reveal window closed");
+        require!(!title.is_empty() && !description.is_empty(), "Title or description is empty");
+
+        let expected = self.answer_commitment(&answer_id).get();
+        let computed = self.compute_answer_commitment(caller.clone(), title.clone(), description.clone(), salt);
+        require!(computed == expected, "Commitment mismatch");
+
+        answer.title = title;
+        answer.description = description;
+        self.register_answer_content(&answer);
+        self.answers(&answer_id).set(&answer);
+        self.answer_commitment(&answer_id).clear();
+
+        self.event_answer_revealed(&answer_id, &answer.question_id, &caller);
+    }
+
+    #[endpoint(enableCommitReveal)]
+    fn enable_commit_reveal(&self, question_id: u64, reveal_period: u64) {
+        let caller = self.blockchain().get_caller();
+        let question = self.questions(&question_id).get();
+        require!(caller == question.creator, "Only creator can enable commit-reveal");
+        require!(question.status == QuestionStatus::Created, "Question already has answers or is closed");
+        require!(reveal_period > 0, "Invalid reveal period");
+        require!(reveal_period <= MAX_REVEAL_PERIOD, "Reveal period too long");
+
+        self.reveal_period(&question_id).set(reveal_period);
+        self.event_commit_reveal_enabled(&question_id, reveal_period);
+    }
+
+    fn create_answer(
+        &self,
+        question_id: u64,
+        title: ManagedBuffer,
+        description: ManagedBuffer,
+    ) -> u64 {
         require!(!self.is_paused().get(), "Contract is paused");
         
         let caller = self.blockchain().get_caller();
@@ -120,7 +544,24 @@ pub trait StackTokenContract {
         let mut question = self.questions(&question_id).get();
         require!(question.status == QuestionStatus::Created || question.status == QuestionStatus::Answered, "Question is closed");
         require!(timestamp < question.deadline, "Question deadline passed");
+        require!(self.puzzle_hash(&question_id).is_empty(), "Use solvePuzzle for puzzle questions");
         require!(caller != question.creator, "Creator cannot answer own question");
+        self.require_not_denylisted(&caller);
+        require!(
+            !self.invite_only(&question_id).get() || self.allowed_answerers(&question_id).contains(&caller),
+            "Not invited to answer"
+        );
+
+        let max_answers = self.max_answers_per_user_per_question().get();
+        let answer_count = self.user_answer_count(&question_id, &caller).update(|count| {
+            *count += 1;
+            *count
+        });
+        require!(max_answers == 0 || answer_count <= max_answers, "Too many answers for this question");
+        self.apply_post_cooldown(&caller);
+
+        let stake = self.call_value().egld();
+        require!(*stake == self.answer_stake(&question_id).get(), "Invalid answer stake");
 
         let aid = self.answer_id().update(|id| {
             *id += 1;
@@ -138,8 +579,17 @@ pub trait StackTokenContract {
             approved_by_creator: false,
         };
 
+        // Commit-reveal answers are checked for duplicates when revealed
+        if self.reveal_period(&question_id).get() == 0 {
+            self.register_answer_content(&answer);
+        }
+
         self.answers(&aid).set(&answer);
         self.answers_by_question(&question_id).insert(aid);
+        if *stake > 0 {
+            self.answer_stake_deposit(&aid).set(&*stake);
+            self.total_answer_stakes().update(|total| *total += &*stake);
+        }
 
         // Update question status to Answered if it was Created
         if question.status == QuestionStatus::Created {
@@ -148,100 +598,161 @@ pub trait StackTokenContract {
         }
 
         self.event_answer_submitted(&aid, &question_id, &caller);
+        aid
     }
 
-    #[endpoint(approveAnswer)]
-    fn approve_answer(&self, question_id: u64, answer_id: u64) {
-        let caller = self.blockchain().get_caller();
-        let timestamp = self.blockchain().get_block_timestamp();
+    #[endpoint(voteAnswer)]
+    fn vote_answer(&self, answer_id: u64) {
+        require!(!self.is_paused().get(), "Contract is paused");
 
-        let mut question = self.questions(&question_id).get();
-        require!(caller == question.creator, "Only creator can approve");
-        require!(timestamp < question.deadline, "Cannot approve after deadline");
-        require!(question.status == QuestionStatus::Created || question.status == QuestionStatus::Answered, "Question is already closed");
+        let caller = self.blockchain().get_caller();
+        self.require_not_denylisted(&caller);
 
         let mut answer = self.answers(&answer_id).get();
-        require!(answer.question_id == question_id, "Answer does not match question");
-
-        question.status = QuestionStatus::AnswerApproved;
-        question.approved_answer_id = Some(answer_id);
-        answer.approved_by_creator = true;
+        require!(caller != answer.creator, "Cannot vote own answer");
+        require!(self.answer_commitment(&answer_id).is_empty(), "Answer not revealed");
+        require!(self.answer_voters(&answer_id).insert(caller.clone()), "Already voted");
 
-        self.questions(&question_id).set(&question);
+        let weight = self.get_vote_weight(caller.clone());
+        answer.votes += weight;
         self.answers(&answer_id).set(&answer);
-        self.send().direct(
-            &answer.creator,
-            &EgldOrEsdtTokenIdentifier::egld(),
-            0u64,
-            &question.locked_amount,
-        );
 
-        self.event_answer_approved(&question_id, &answer_id, &answer.creator);
+        let votes_received = self.votes_received(&answer.creator).update(|count| {
+            *count += weight as u64;
+            *count
+        });
+        let votes_cast = self.votes_cast(&caller).update(|count| {
+            *count += 1;
+            *count
+        });
+        self.check_badges(&answer.creator, BadgeCriterion::VotesReceived, votes_received);
+        self.check_badges(&caller, BadgeCriterion::VotesCast, votes_cast);
+
+        self.event_answer_voted(&answer_id, &answer.question_id, &caller);
     }
 
-    #[endpoint(refundQuestion)]
-    fn refund_question(&self, question_id: u64) {
+    // Comments: short clarification threads on questions and answers, optionally replying to
+    // an earlier comment on the same target
+    #[endpoint(addComment)]
+    fn add_comment(&self, target: CommentTarget, content: ManagedBuffer, reply_to: OptionalValue<u64>) -> u64 {
+        require!(!self.is_paused().get(), "Contract is paused");
         let caller = self.blockchain().get_caller();
-        self.do_refund_question(question_id, Some(caller));
-    }
+        self.require_not_denylisted(&caller);
+        require!(!content.is_empty(), "Comment is empty");
+        require!(content.len() <= MAX_COMMENT_LENGTH, "Comment too long");
+        match &target {
+            CommentTarget::Question(question_id) => {
+                require!(!self.questions(question_id).is_empty(), "Question does not exist")
+            },
+            CommentTarget::Answer(answer_id) => {
+                require!(!self.answers(answer_id).is_empty(), "Answer does not exist")
+            },
+        }
 
-    // New: Internal refund logic, can be called by anyone or system
-    fn do_refund_question(&self, question_id: u64, caller_opt: Option<ManagedAddress>) {
-        let timestamp = self.blockchain().get_block_timestamp();
-        let mut question = self.questions(&question_id).get();
-        let creator = question.creator.clone();
-        let caller = caller_opt.unwrap_or_else(|| creator.clone());
+        let reply_to = reply_to.into_option();
+        if let Some(parent_id) = reply_to {
+            require!(!self.comments(&parent_id).is_empty(), "Parent comment does not exist");
+            require!(self.comments(&parent_id).get().target == target, "Parent comment is on another target");
+        }
 
-        require!(caller == creator, "Only creator can refund");
-        require!(timestamp >= question.deadline, "Deadline not reached");
-        require!(question.status == QuestionStatus::Created || question.status == QuestionStatus::Answered, "Question already handled");
+        let comment_id = self.comment_id().update(|id| {
+            *id += 1;
+            *id
+        });
+        let comment = Comment {
+            comment_id,
+            author: caller.clone(),
+            target: target.clone(),
+            reply_to,
+            content,
+            created_at: self.blockchain().get_block_timestamp(),
+            hidden: false,
+        };
+        self.comments(&comment_id).set(&comment);
+        self.comments_by_target(&target).push(&comment_id);
 
-        question.status = QuestionStatus::Expired;
-        self.questions(&question_id).set(&question);
-        self.send().direct(
-            &creator,
-            &EgldOrEsdtTokenIdentifier::egld(),
-            0u64,
-            &question.locked_amount,
-        );
+        self.event_comment_added(&comment_id, &caller, &target);
+        comment_id
+    }
 
-        self.event_tokens_refunded(&question_id, &creator);
+    #[endpoint(setCommentHidden)]
+    fn set_comment_hidden(&self, comment_id: u64, hidden: bool) {
+        self.require_moderator();
+        require!(!self.comments(&comment_id).is_empty(), "Comment does not exist");
+        self.comments(&comment_id).update(|comment| comment.hidden = hidden);
+        self.event_comment_hidden(&comment_id, hidden);
     }
 
-    // New: Expire all questions past deadline (can be called by anyone)
-    #[endpoint(expireQuestions)]
-    fn expire_questions(&self) {
-        let now = self.blockchain().get_block_timestamp();
-        let total_questions = self.question_id().get();
-        for qid in 1..=total_questions {
-            let question = self.questions(&qid).get();
-            if (question.status == QuestionStatus::Created || question.status == QuestionStatus::Answered)
-                && now >= question.deadline {
-                // Refund to creator
-                self.do_refund_question(qid, None);
+    // Pages over the target's comments in posting order; hidden comments are skipped but still
+    // count towards the offset so pages stay stable
+    #[view(getComments)]
+    fn get_comments(&self, target: CommentTarget, offset: usize, limit: usize) -> MultiValueEncoded<Comment<Self::Api>> {
+        let mut result = MultiValueEncoded::new();
+        let comment_ids = self.comments_by_target(&target);
+        let limit = core::cmp::min(limit, MAX_COMMENTS_PAGE_SIZE);
+        let end = core::cmp::min(offset.saturating_add(limit), comment_ids.len());
+        for index in offset.saturating_add(1)..=end {
+            let comment = self.comments(&comment_ids.get(index)).get();
+            if !comment.hidden {
+                result.push(comment);
             }
         }
+        result
     }
 
-    #[view(getAllOpenQuestions)]
-    fn get_all_open_questions(&self) -> MultiValueEncoded<Question<Self::Api>> {
-        let now = self.blockchain().get_block_timestamp();
-        let mut results = MultiValueEncoded::new();
-        let total_questions = self.question_id().get();
-        
-        for qid in 1..=total_questions {
-            let question = self.questions(&qid).get();
-            if (question.status == QuestionStatus::Created || question.status == QuestionStatus::Answered) && question.deadline > now {
-                results.push(question);
+    #[view(getCommentCount)]
+    fn get_comment_count(&self, target: CommentTarget) -> usize {
+        self.comments_by_target(&target).len()
+    }
+
+    // Profiles: display names are unique ignoring case and extra whitespace; skills use the same
+    // format as question tags and feed the skills directory
+    #[endpoint(setProfile)]
+    fn set_profile(&self, display_name: ManagedBuffer, bio_uri: ManagedBuffer, skills: MultiValueEncoded<ManagedBuffer>) {
+        let caller = self.blockchain().get_caller();
+        self.require_not_denylisted(&caller);
+        require!(
+            !display_name.is_empty() && display_name.len() <= MAX_DISPLAY_NAME_LENGTH,
+            "Invalid display name"
+        );
+        require!(bio_uri.len() <= MAX_BIO_URI_LENGTH, "Bio URI too long");
+        require!(skills.len() <= MAX_SKILLS_PER_PROFILE, "Too many skills");
+
+        let name_key = self.normalize_content(&display_name);
+        require!(!name_key.is_empty(), "Invalid display name");
+        let name_owner = self.display_name_owner(&name_key);
+        require!(name_owner.is_empty() || name_owner.get() == caller, "Display name already taken");
+
+        let profile_mapper = self.profiles(&caller);
+        if !profile_mapper.is_empty() {
+            let old_profile = profile_mapper.get();
+            self.display_name_owner(&self.normalize_content(&old_profile.display_name)).clear();
+            for skill in old_profile.skills.iter() {
+                self.users_by_skill(&skill).swap_remove(&caller);
             }
         }
-        
-        results
+
+        let mut skill_list = ManagedVec::new();
+        for skill in skills {
+            self.require_valid_tag(&skill);
+            if self.users_by_skill(&skill).insert(caller.clone()) {
+                skill_list.push(skill);
+            }
+        }
+        self.display_name_owner(&name_key).set(&caller);
+        profile_mapper.set(&Profile {
+            display_name: display_name.clone(),
+            bio_uri,
+            skills: skill_list,
+            updated_at: self.blockchain().get_block_timestamp(),
+        });
+
+        self.event_profile_updated(&caller, &display_name);
     }
 
-    #[view(getQuestionDetails)]
-    fn get_question_details(&self, question_id: u64) -> OptionalValue<Question<Self::Api>> {
-        let mapper = self.questions(&question_id);
+    #[view(getProfile)]
+    fn get_profile(&self, address: ManagedAddress) -> OptionalValue<Profile<Self::Api>> {
+        let mapper = self.profiles(&address);
         if mapper.is_empty() {
             OptionalValue::None
         } else {
@@ -249,31 +760,1908 @@ pub trait StackTokenContract {
         }
     }
 
-    #[view(getAnswersForQuestion)]
-    fn get_answers_for_question(&self, question_id: u64) -> MultiValueEncoded<Answer<Self::Api>> {
-        let answer_ids = self.answers_by_question(&question_id);
-        answer_ids
-            .iter()
-            .filter_map(|aid| {
-                let mapper = self.answers(&aid);
-                if mapper.is_empty() {
-                    None
-                } else {
-                    Some(mapper.get())
-                }
-            })
-            .collect()
+    #[view(getUsersBySkill)]
+    fn get_users_by_skill(&self, skill: ManagedBuffer) -> MultiValueEncoded<ManagedAddress> {
+        self.users_by_skill(&skill).iter().collect()
     }
 
-    // Helper function to check if a question should be marked as expired
-    fn check_and_update_question_status(&self, question_id: u64) -> QuestionStatus {
-        let now = self.blockchain().get_block_timestamp();
-        let question = self.questions(&question_id).get();
-        
-        // If deadline passed and question is not closed, it's expired
-        if now >= question.deadline && 
-           (question.status == QuestionStatus::Created || question.status == QuestionStatus::Answered) {
-            // Don't automatically change to expired - let creator decide to refund
+    #[view(getAddressByDisplayName)]
+    fn get_address_by_display_name(&self, display_name: ManagedBuffer) -> OptionalValue<ManagedAddress> {
+        let mapper = self.display_name_owner(&self.normalize_content(&display_name));
+        if mapper.is_empty() {
+            OptionalValue::None
+        } else {
+            OptionalValue::Some(mapper.get())
+        }
+    }
+
+    // Tips: anyone can reward an answer with EGLD or a fungible ESDT, forwarded to its author
+    #[payable("*")]
+    #[endpoint(tipAnswer)]
+    fn tip_answer(&self, answer_id: u64) {
+        require!(!self.is_paused().get(), "Contract is paused");
+        require!(!self.answers(&answer_id).is_empty(), "Answer does not exist");
+
+        let caller = self.blockchain().get_caller();
+        let answer = self.answers(&answer_id).get();
+        require!(caller != answer.creator, "Cannot tip own answer");
+
+        let payment = self.call_value().egld_or_single_esdt();
+        require!(payment.token_nonce == 0, "Only fungible tokens can be tipped");
+        require!(payment.amount > 0, "Tip amount must be positive");
+
+        self.tip_total(&answer_id, &payment.token_identifier)
+            .update(|total| *total += &payment.amount);
+        self.tip_tokens(&answer_id).insert(payment.token_identifier.clone());
+        self.payout(&answer.creator, &payment.token_identifier, &payment.amount, false);
+
+        self.event_tip_sent(&answer_id, &caller, &answer.creator, &payment.token_identifier, &payment.amount);
+    }
+
+    // Sponsored tag pools: a sponsor deposits EGLD for a tag and every new question carrying that
+    // tag is topped up by a fixed amount or a ratio of its bounty while the pool lasts
+    #[payable("EGLD")]
+    #[endpoint(createSponsorPool)]
+    fn create_sponsor_pool(&self, tag: ManagedBuffer, match_amount: BigUint, match_ratio_bps: u64) -> u64 {
+        require!(!self.is_paused().get(), "Contract is paused");
+        let caller = self.blockchain().get_caller();
+        self.require_not_denylisted(&caller);
+        self.require_valid_tag(&tag);
+        require!(
+            (match_amount > 0) != (match_ratio_bps > 0),
+            "Set either a match amount or a match ratio"
+        );
+        require!(match_ratio_bps <= BPS_DENOMINATOR, "Match ratio too high");
+        if match_ratio_bps > 0 {
+            require!(match_ratio_bps >= MIN_SPONSOR_MATCH_BPS, "Match ratio too low");
+        } else {
+            require!(match_amount >= MIN_SPONSOR_MATCH_AMOUNT, "Match amount too low");
+        }
+
+        require!(
+            self.pools_by_tag(&tag).len() < MAX_POOLS_PER_TAG,
+            "Too many sponsor pools for tag"
+        );
+
+        let deposit = self.call_value().egld().clone();
+        require!(deposit >= MIN_SPONSOR_POOL_DEPOSIT, "Pool deposit too low");
+
+        let pool_id = self.sponsor_pool_id().update(|id| {
+            *id += 1;
+            *id
+        });
+        let pool = SponsorPool {
+            pool_id,
+            sponsor: caller.clone(),
+            tag: tag.clone(),
+            balance: deposit.clone(),
+            match_amount,
+            match_ratio_bps,
+        };
+        self.sponsor_pools(&pool_id).set(&pool);
+        self.pools_by_tag(&tag).insert(pool_id);
+        self.total_sponsor_pool_balance().update(|total| *total += &deposit);
+
+        self.event_sponsor_pool_created(&pool_id, &caller, &tag, &deposit);
+        pool_id
+    }
+
+    #[payable("EGLD")]
+    #[endpoint(fundSponsorPool)]
+    fn fund_sponsor_pool(&self, pool_id: u64) {
+        let caller = self.blockchain().get_caller();
+        let mut pool = self.get_sponsor_pool_checked(pool_id);
+        require!(caller == pool.sponsor, "Only sponsor can fund the pool");
+
+        let deposit = self.call_value().egld().clone();
+        require!(deposit >= MIN_SPONSOR_POOL_DEPOSIT, "Pool deposit too low");
+
+        pool.balance += &deposit;
+        self.sponsor_pools(&pool_id).set(&pool);
+        self.activate_sponsor_pool(&pool);
+        self.total_sponsor_pool_balance().update(|total| *total += &deposit);
+        self.event_sponsor_pool_funded(&pool_id, &deposit);
+    }
+
+    #[endpoint(withdrawSponsorPool)]
+    fn withdraw_sponsor_pool(&self, pool_id: u64) {
+        let caller = self.blockchain().get_caller();
+        let mut pool = self.get_sponsor_pool_checked(pool_id);
+        require!(caller == pool.sponsor, "Only sponsor can withdraw");
+        require!(pool.balance > 0, "Pool is empty");
+
+        let amount = core::mem::take(&mut pool.balance);
+        self.sponsor_pools(&pool_id).set(&pool);
+        self.pools_by_tag(&pool.tag).swap_remove(&pool_id);
+        self.total_sponsor_pool_balance().update(|total| *total -= &amount);
+        self.send().direct_egld(&caller, &amount);
+        self.event_sponsor_pool_withdrawn(&pool_id, &amount);
+    }
+
+    fn get_sponsor_pool_checked(&self, pool_id: u64) -> SponsorPool<Self::Api> {
+        require!(!self.sponsor_pools(&pool_id).is_empty(), "Pool does not exist");
+        self.sponsor_pools(&pool_id).get()
+    }
+
+    fn require_valid_tag(&self, tag: &ManagedBuffer) {
+        require!(!tag.is_empty() && tag.len() <= MAX_TAG_LENGTH, "Invalid tag");
+    }
+
+    fn set_question_tags(&self, question_id: u64, tags: MultiValueEncoded<ManagedBuffer>) {
+        require!(tags.len() <= MAX_TAGS_PER_QUESTION, "Too many tags");
+        let mut question_tags = self.question_tags(&question_id);
+        for tag in tags {
+            self.require_valid_tag(&tag);
+            question_tags.insert(tag);
+        }
+    }
+
+    // Tops up a new question from every pool sponsoring one of its tags; a pool that can't
+    // cover its full match contributes whatever it has left
+    fn apply_sponsor_matches(&self, question_id: u64, payment: &BigUint) -> BigUint {
+        let now = self.blockchain().get_block_timestamp();
+        let mut total_matched = BigUint::zero();
+        let mut drained_pools = ManagedVec::<Self::Api, u64>::new();
+        for tag in self.question_tags(&question_id).iter() {
+            for pool_id in self.pools_by_tag(&tag).iter() {
+                let mut pool = self.sponsor_pools(&pool_id).get();
+                let mut amount = if pool.match_ratio_bps > 0 {
+                    payment * pool.match_ratio_bps / BPS_DENOMINATOR
+                } else {
+                    pool.match_amount.clone()
+                };
+                if amount > pool.balance {
+                    amount = pool.balance.clone();
+                }
+                if amount == 0 {
+                    continue;
+                }
+
+                pool.balance -= &amount;
+                self.sponsor_pools(&pool_id).set(&pool);
+                if pool.balance == 0 {
+                    drained_pools.push(pool_id);
+                }
+                let sponsor_match = SponsorMatch {
+                    pool_id,
+                    question_id,
+                    amount: amount.clone(),
+                    timestamp: now,
+                };
+                self.pool_matches(&pool_id).push(&sponsor_match);
+                self.question_sponsor_matches(&question_id).push(&sponsor_match);
+                self.total_sponsor_pool_balance().update(|total| *total -= &amount);
+                self.event_sponsor_match(&pool_id, &question_id, &amount);
+                total_matched += amount;
+            }
+        }
+        // Drained pools stop being scanned until the sponsor refills them
+        for pool_id in drained_pools.iter() {
+            let pool = self.sponsor_pools(&pool_id).get();
+            self.pools_by_tag(&pool.tag).swap_remove(&pool_id);
+        }
+        total_matched
+    }
+
+    // Puts the sponsor top-ups of a refunded question back into their pools
+    fn return_sponsor_matches(&self, question_id: u64) -> BigUint {
+        let mut total_returned = BigUint::zero();
+        for sponsor_match in self.question_sponsor_matches(&question_id).iter() {
+            let mut pool = self.sponsor_pools(&sponsor_match.pool_id).get();
+            pool.balance += &sponsor_match.amount;
+            self.sponsor_pools(&sponsor_match.pool_id).set(&pool);
+            self.activate_sponsor_pool(&pool);
+            total_returned += &sponsor_match.amount;
+        }
+        self.total_sponsor_pool_balance().update(|total| *total += &total_returned);
+        total_returned
+    }
+
+    // Re-lists a refilled pool under its tag; a pool that finds the tag full
+    // keeps its balance and can still be withdrawn by the sponsor
+    fn activate_sponsor_pool(&self, pool: &SponsorPool<Self::Api>) {
+        let mut pools = self.pools_by_tag(&pool.tag);
+        if pool.balance > 0 && !pools.contains(&pool.pool_id) && pools.len() < MAX_POOLS_PER_TAG {
+            pools.insert(pool.pool_id);
+        }
+    }
+
+    #[view(getSponsorPool)]
+    fn get_sponsor_pool(&self, pool_id: u64) -> OptionalValue<SponsorPool<Self::Api>> {
+        let mapper = self.sponsor_pools(&pool_id);
+        if mapper.is_empty() {
+            OptionalValue::None
+        } else {
+            OptionalValue::Some(mapper.get())
+        }
+    }
+
+    #[view(getPoolsByTag)]
+    fn get_pools_by_tag(&self, tag: ManagedBuffer) -> MultiValueEncoded<SponsorPool<Self::Api>> {
+        self.pools_by_tag(&tag)
+            .iter()
+            .map(|pool_id| self.sponsor_pools(&pool_id).get())
+            .collect()
+    }
+
+    #[view(getPoolMatches)]
+    fn get_pool_matches(&self, pool_id: u64) -> MultiValueEncoded<SponsorMatch<Self::Api>> {
+        self.pool_matches(&pool_id).iter().collect()
+    }
+
+    #[view(getQuestionTags)]
+    fn get_question_tags(&self, question_id: u64) -> MultiValueEncoded<ManagedBuffer> {
+        self.question_tags(&question_id).iter().collect()
+    }
+
+    #[view(getAnswerTips)]
+    fn get_answer_tips(
+        &self,
+        answer_id: u64,
+    ) -> MultiValueEncoded<MultiValue2<EgldOrEsdtTokenIdentifier, BigUint>> {
+        let mut result = MultiValueEncoded::new();
+        for token in self.tip_tokens(&answer_id).iter() {
+            let total = self.tip_total(&answer_id, &token).get();
+            result.push((token, total).into());
+        }
+        result
+    }
+
+    #[endpoint(approveAnswer)]
+    fn approve_answer(&self, question_id: u64, answer_id: u64) {
+        let caller = self.blockchain().get_caller();
+        let timestamp = self.blockchain().get_block_timestamp();
+
+        let question = self.questions(&question_id).get();
+        let reviewers = self.question_reviewers(&question_id);
+        if reviewers.is_empty() {
+            require!(caller == question.creator, "Only creator can approve");
+        } else {
+            require!(reviewers.contains(&caller), "Only reviewer can approve");
+        }
+        // A delivered job can still be approved after the deadline, during its review window
+        if question.status == QuestionStatus::Delivered {
+            require!(
+                self.job_assignment(&question_id).get().answer_id == Some(answer_id),
+                "Answer is not the delivery"
+            );
+        } else {
+            require!(timestamp < self.closing_time(&question), "Cannot approve after deadline");
+            require!(question.status == QuestionStatus::Created || question.status == QuestionStatus::Answered, "Question is already closed");
+        }
+
+        let answer = self.answers(&answer_id).get();
+        require!(answer.question_id == question_id, "Answer does not match question");
+        require!(!self.answer_marked_spam(&answer_id).get(), "Answer marked as spam");
+        require!(self.answer_commitment(&answer_id).is_empty(), "Answer not revealed");
+        require!(self.question_milestones(&question_id).is_empty(), "Approve milestones individually");
+        require!(self.competition_schedule(&question_id).is_empty(), "Use rankAnswers for competition questions");
+
+        if !reviewers.is_empty() {
+            require!(caller != answer.creator, "Reviewer cannot approve own answer");
+            require!(self.reviewer_approvals(&answer_id).insert(caller.clone()), "Already approved");
+            let approvals = self.reviewer_approvals(&answer_id).len() as u32;
+            self.event_reviewer_approved(&question_id, &answer_id, &caller);
+            if approvals < self.required_approvals(&question_id).get() {
+                return;
+            }
+        }
+
+        self.finalize_approval(question, answer);
+    }
+
+    // Delegated reviewers: the creator hands approval to a set of reviewers before any answer
+    // arrives; the payout fires once `required_approvals` of them approve the same answer
+    #[endpoint(setReviewers)]
+    fn set_reviewers(&self, question_id: u64, required_approvals: u32, reviewers: MultiValueEncoded<ManagedAddress>) {
+        let caller = self.blockchain().get_caller();
+        let question = self.questions(&question_id).get();
+        require!(caller == question.creator, "Only creator can set reviewers");
+        require!(question.status == QuestionStatus::Created, "Question already has answers or is closed");
+        // Milestone and competition payouts are released by the creator alone
+        require!(self.question_milestones(&question_id).is_empty(), "Milestone questions cannot have reviewers");
+        require!(
+            self.competition_schedule(&question_id).is_empty(),
+            "Competition questions cannot have reviewers"
+        );
+        require!(reviewers.len() <= MAX_REVIEWERS, "Too many reviewers");
+
+        let mut mapper = self.question_reviewers(&question_id);
+        mapper.clear();
+        for reviewer in reviewers {
+            mapper.insert(reviewer);
+        }
+        require!(
+            required_approvals >= 1 && required_approvals as usize <= mapper.len(),
+            "Invalid required approvals"
+        );
+        self.required_approvals(&question_id).set(required_approvals);
+
+        self.event_reviewers_set(&question_id, required_approvals, mapper.len() as u32);
+    }
+
+    // Closes the question in favour of the answer: pays out the escrow, returns stakes and
+    // hands out StackToken rewards and badges
+    fn finalize_approval(&self, mut question: Question<Self::Api>, mut answer: Answer<Self::Api>) {
+        let question_id = question.question_id;
+        let answer_id = answer.answer_id;
+
+        question.status = QuestionStatus::AnswerApproved;
+        question.approved_answer_id = Some(answer_id);
+        answer.approved_by_creator = true;
+
+        self.questions(&question_id).set(&question);
+        self.answers(&answer_id).set(&answer);
+        self.release_open_question_slot(&question.creator);
+        let answer_amount = self.decayed_payout(&question, answer.created_at);
+        let remainder = &question.locked_amount - &answer_amount;
+        if self.vesting_period(&question_id).get() > 0 {
+            self.start_vesting(question_id, &answer.creator, &answer_amount);
+        } else {
+            self.pay_answerer(&answer.creator, &answer_amount);
+        }
+        if remainder > 0 {
+            self.track_refund(&remainder);
+            self.payout(&question.creator, &EgldOrEsdtTokenIdentifier::egld(), &remainder, false);
+        }
+        self.return_answer_stakes(question_id, false);
+        self.reward_approval(&question.creator, &answer.creator);
+
+        self.event_answer_approved(&question_id, &answer_id, &answer.creator);
+    }
+
+    // Share of the bounty earned by an answer submitted at `submitted_at`
+    fn decayed_payout(&self, question: &Question<Self::Api>, submitted_at: u64) -> BigUint {
+        let decay_mapper = self.bounty_decay(&question.question_id);
+        if decay_mapper.is_empty() {
+            return question.locked_amount.clone();
+        }
+
+        let elapsed = submitted_at.saturating_sub(question.created_at);
+        let payout_bps = match decay_mapper.get() {
+            DecayCurve::Linear { duration, min_payout_bps } => {
+                if elapsed >= duration {
+                    min_payout_bps
+                } else {
+                    BPS_DENOMINATOR - (BPS_DENOMINATOR - min_payout_bps) * elapsed / duration
+                }
+            },
+            DecayCurve::Stepwise { step_duration, step_bps, min_payout_bps } => {
+                let reduction = (elapsed / step_duration).saturating_mul(step_bps);
+                core::cmp::max(BPS_DENOMINATOR.saturating_sub(reduction), min_payout_bps)
+            },
+        };
+        &question.locked_amount * payout_bps / BPS_DENOMINATOR
+    }
+
+    // Gross payout (before the platform fee) an answer submitted now would earn if approved
+    #[view(getCurrentPayout)]
+    fn get_current_payout(&self, question_id: u64) -> BigUint {
+        let question = self.questions(&question_id).get();
+        self.decayed_payout(&question, self.blockchain().get_block_timestamp())
+    }
+
+    #[view(getBountyDecay)]
+    fn get_bounty_decay(&self, question_id: u64) -> OptionalValue<DecayCurve> {
+        let mapper = self.bounty_decay(&question_id);
+        if mapper.is_empty() {
+            OptionalValue::None
+        } else {
+            OptionalValue::Some(mapper.get())
+        }
+    }
+
+    fn reward_approval(&self, asker: &ManagedAddress, answerer: &ManagedAddress) {
+        let (answerer_reward, asker_reward) = self.get_stack_token_rewards().into_tuple();
+        self.mint_stack_token_reward(answerer, &answerer_reward);
+        self.mint_stack_token_reward(asker, &asker_reward);
+
+        let approvals = self.approved_answer_count(answerer).update(|count| {
+            *count += 1;
+            *count
+        });
+        self.check_badges(answerer, BadgeCriterion::ApprovedAnswers, approvals);
+    }
+
+    // Pays the ranked answers their scheduled share. Every place must be filled while there are
+    // eligible answers for it; shares of places nobody could fill and rounding dust go back to
+    // the creator. Only first place earns StackToken rewards and badge progress
+    #[endpoint(rankAnswers)]
+    fn rank_answers(&self, question_id: u64, ordered_ids: MultiValueEncoded<u64>) {
+        let caller = self.blockchain().get_caller();
+        let timestamp = self.blockchain().get_block_timestamp();
+        let mut question = self.questions(&question_id).get();
+        require!(caller == question.creator, "Only creator can rank answers");
+        require!(timestamp < self.closing_time(&question), "Cannot approve after deadline");
+        require!(question.status == QuestionStatus::Created || question.status == QuestionStatus::Answered, "Question is already closed");
+
+        let schedule = self.competition_schedule(&question_id);
+        require!(!schedule.is_empty(), "Question is not a competition");
+        require!(!ordered_ids.is_empty(), "No answers ranked");
+        require!(ordered_ids.len() <= schedule.len(), "More answers than payout places");
+
+        let mut eligible = 0usize;
+        for answer_id in self.answers_by_question(&question_id).iter() {
+            if eligible == schedule.len() {
+                break;
+            }
+            if !self.answer_marked_spam(&answer_id).get() && self.answer_commitment(&answer_id).is_empty() {
+                eligible += 1;
+            }
+        }
+        require!(ordered_ids.len() == eligible, "All payout places must be ranked");
+
+        let ordered_ids = ordered_ids.to_vec();
+        for (index, answer_id) in ordered_ids.iter().enumerate() {
+            require!(
+                self.answers_by_question(&question_id).contains(&answer_id),
+                "Answer does not match question"
+            );
+            require!(!self.answer_marked_spam(&answer_id).get(), "Answer marked as spam");
+            require!(self.answer_commitment(&answer_id).is_empty(), "Answer not revealed");
+            require!(
+                !ordered_ids.iter().skip(index + 1).any(|other| other == answer_id),
+                "Answer ranked twice"
+            );
+        }
+
+        let total = question.locked_amount.clone();
+        let mut paid = BigUint::zero();
+        for (index, answer_id) in ordered_ids.iter().enumerate() {
+            let rank = index + 1;
+            let amount = &total * schedule.get(rank) / BPS_DENOMINATOR;
+            let mut answer = self.answers(&answer_id).get();
+            answer.approved_by_creator = true;
+            self.answers(&answer_id).set(&answer);
+            self.pay_answerer(&answer.creator, &amount);
+            if rank == 1 {
+                self.reward_approval(&question.creator, &answer.creator);
+            }
+            self.event_competition_winner(&question_id, &answer_id, rank, &amount);
+            paid += amount;
+        }
+
+        question.status = QuestionStatus::AnswerApproved;
+        question.approved_answer_id = Some(ordered_ids.get(0));
+        question.locked_amount = &total - &paid;
+        self.questions(&question_id).set(&question);
+        self.release_open_question_slot(&question.creator);
+        if question.locked_amount > 0 {
+            self.track_refund(&question.locked_amount);
+            self.payout(&question.creator, &EgldOrEsdtTokenIdentifier::egld(), &question.locked_amount, false);
+        }
+        self.return_answer_stakes(question_id, false);
+    }
+
+    #[view(getPayoutSchedule)]
+    fn get_payout_schedule(&self, question_id: u64) -> MultiValueEncoded<u64> {
+        self.competition_schedule(&question_id).iter().collect()
+    }
+
+    // Releases part of a question's escrow to an answerer, minus the platform fee
+    fn pay_answerer(&self, answerer: &ManagedAddress, amount: &BigUint) {
+        let fee = self.get_platform_fee_for(answerer.clone(), amount.clone());
+        let answerer_amount = amount - &fee;
+        let egld = EgldOrEsdtTokenIdentifier::egld();
+        self.total_locked(&egld).update(|total| *total -= amount);
+        self.total_paid_out(&egld).update(|total| *total += &answerer_amount);
+        self.payout(answerer, &egld, &answerer_amount, false);
+        self.payout(&self.get_treasury(), &egld, &fee, false);
+    }
+
+    // Vested payouts: the fee is taken at approval and the rest unlocks linearly for the answerer.
+    // The amount stays counted as locked until it is claimed
+    fn start_vesting(&self, question_id: u64, answerer: &ManagedAddress, amount: &BigUint) {
+        let fee = self.get_platform_fee_for(answerer.clone(), amount.clone());
+        let egld = EgldOrEsdtTokenIdentifier::egld();
+        self.total_locked(&egld).update(|total| *total -= &fee);
+        self.payout(&self.get_treasury(), &egld, &fee, false);
+
+        let now = self.blockchain().get_block_timestamp();
+        let vesting = Vesting {
+            beneficiary: answerer.clone(),
+            total_amount: amount - &fee,
+            claimed_amount: BigUint::zero(),
+            start: now,
+            end: now.saturating_add(self.vesting_period(&question_id).get()),
+            frozen_at: 0,
+            dispute_resolved: false,
+        };
+        self.event_vesting_started(&question_id, answerer, vesting.end, &vesting.total_amount);
+        self.vestings(&question_id).set(vesting);
+    }
+
+    fn vested_amount(&self, vesting: &Vesting<Self::Api>) -> BigUint {
+        let mut now = self.blockchain().get_block_timestamp();
+        if vesting.frozen_at > 0 && vesting.frozen_at < now {
+            now = vesting.frozen_at;
+        }
+        if now >= vesting.end {
+            return vesting.total_amount.clone();
+        }
+        &vesting.total_amount * (now - vesting.start) / (vesting.end - vesting.start)
+    }
+
+    // Set by the question creator before any answer is submitted
+    #[endpoint(setVestingPeriod)]
+    fn set_vesting_period(&self, question_id: u64, period: u64) {
+        let caller = self.blockchain().get_caller();
+        let question = self.questions(&question_id).get();
+        require!(caller == question.creator, "Only creator can set vesting");
+        require!(question.status == QuestionStatus::Created, "Question already has answers or is closed");
+        require!(period <= MAX_VESTING_PERIOD, "Vesting period too long");
+        // Milestone and competition payouts are released directly, not through a vesting schedule
+        require!(self.question_milestones(&question_id).is_empty(), "Milestone questions cannot vest");
+        require!(self.competition_schedule(&question_id).is_empty(), "Competition questions cannot vest");
+
+        self.vesting_period(&question_id).set(period);
+        self.event_vesting_period_set(&question_id, period);
+    }
+
+    #[endpoint(claimVested)]
+    fn claim_vested(&self, question_id: u64) {
+        let caller = self.blockchain().get_caller();
+        require!(!self.vestings(&question_id).is_empty(), "No vesting for question");
+        let mut vesting = self.vestings(&question_id).get();
+        require!(caller == vesting.beneficiary, "Only beneficiary can claim");
+
+        let claimable = self.vested_amount(&vesting) - &vesting.claimed_amount;
+        require!(claimable > 0, "Nothing to claim");
+
+        vesting.claimed_amount += &claimable;
+        self.vestings(&question_id).set(&vesting);
+        let egld = EgldOrEsdtTokenIdentifier::egld();
+        self.total_locked(&egld).update(|total| *total -= &claimable);
+        self.total_paid_out(&egld).update(|total| *total += &claimable);
+        self.payout(&caller, &egld, &claimable, false);
+
+        self.event_vested_claimed(&question_id, &caller, &claimable);
+    }
+
+    // Freezes the part that hasn't vested yet until a moderator resolves the dispute
+    #[endpoint(disputeVesting)]
+    fn dispute_vesting(&self, question_id: u64) {
+        let caller = self.blockchain().get_caller();
+        let question = self.questions(&question_id).get();
+        require!(caller == question.creator, "Only creator can dispute");
+        require!(!self.vestings(&question_id).is_empty(), "No vesting for question");
+        let mut vesting = self.vestings(&question_id).get();
+        let now = self.blockchain().get_block_timestamp();
+        require!(now < vesting.end, "Vesting already finished");
+        require!(vesting.frozen_at == 0, "Vesting already disputed");
+        // A vesting can be disputed once; a ruling for the answerer is final
+        require!(!vesting.dispute_resolved, "Vesting dispute already resolved");
+
+        vesting.frozen_at = now;
+        self.vestings(&question_id).set(&vesting);
+        self.event_vesting_disputed(&question_id, now);
+    }
+
+    // Either lets vesting continue on its original schedule or returns the frozen part to the creator
+    #[endpoint(resolveVestingDispute)]
+    fn resolve_vesting_dispute(&self, question_id: u64, release_to_answerer: bool) {
+        self.require_moderator();
+        require!(!self.vestings(&question_id).is_empty(), "No vesting for question");
+        let mut vesting = self.vestings(&question_id).get();
+        require!(vesting.frozen_at > 0, "Vesting is not disputed");
+        require!(!vesting.dispute_resolved, "Vesting dispute already resolved");
+
+        vesting.dispute_resolved = true;
+        if release_to_answerer {
+            vesting.frozen_at = 0;
+        } else {
+            let vested = self.vested_amount(&vesting);
+            let frozen = &vesting.total_amount - &vested;
+            vesting.total_amount = vested;
+            vesting.end = vesting.frozen_at;
+
+            let question = self.questions(&question_id).get();
+            self.track_refund(&frozen);
+            self.payout(&question.creator, &EgldOrEsdtTokenIdentifier::egld(), &frozen, false);
+        }
+        self.vestings(&question_id).set(&vesting);
+        self.event_vesting_dispute_resolved(&question_id, release_to_answerer);
+    }
+
+    #[view(getVesting)]
+    fn get_vesting(&self, question_id: u64) -> OptionalValue<Vesting<Self::Api>> {
+        let mapper = self.vestings(&question_id);
+        if mapper.is_empty() {
+            OptionalValue::None
+        } else {
+            OptionalValue::Some(mapper.get())
+        }
+    }
+
+    #[view(getVestedClaimable)]
+    fn get_vested_claimable(&self, question_id: u64) -> BigUint {
+        let mapper = self.vestings(&question_id);
+        if mapper.is_empty() {
+            return BigUint::zero();
+        }
+        let vesting = mapper.get();
+        self.vested_amount(&vesting) - &vesting.claimed_amount
+    }
+
+    #[view(getVestingPeriod)]
+    fn get_vesting_period(&self, question_id: u64) -> u64 {
+        self.vesting_period(&question_id).get()
+    }
+
+    // Pays a single milestone; the question closes once every milestone is approved. StackToken
+    // rewards and badges are left to whole-question approvals so splitting can't farm them
+    #[endpoint(approveMilestone)]
+    fn approve_milestone(&self, question_id: u64, milestone_id: usize, answer_id: u64) {
+        let caller = self.blockchain().get_caller();
+        let timestamp = self.blockchain().get_block_timestamp();
+
+        let mut question = self.questions(&question_id).get();
+        require!(caller == question.creator, "Only creator can approve");
+        require!(timestamp < self.closing_time(&question), "Cannot approve after deadline");
+        require!(question.status == QuestionStatus::Created || question.status == QuestionStatus::Answered, "Question is already closed");
+
+        let milestones = self.question_milestones(&question_id);
+        require!(milestone_id >= 1 && milestone_id <= milestones.len(), "Milestone does not exist");
+        let mut milestone = milestones.get(milestone_id);
+        require!(milestone.status == MilestoneStatus::Pending, "Milestone already handled");
+
+        let answer = self.answers(&answer_id).get();
+        require!(answer.question_id == question_id, "Answer does not match question");
+        require!(!self.answer_marked_spam(&answer_id).get(), "Answer marked as spam");
+        require!(self.answer_commitment(&answer_id).is_empty(), "Answer not revealed");
+
+        milestone.status = MilestoneStatus::Approved;
+        milestone.answer_id = Some(answer_id);
+        self.question_milestones(&question_id).set(milestone_id, &milestone);
+
+        question.locked_amount -= &milestone.amount;
+        let all_approved = self
+            .question_milestones(&question_id)
+            .iter()
+            .all(|m| m.status == MilestoneStatus::Approved);
+        if all_approved {
+            question.status = QuestionStatus::AnswerApproved;
+            question.approved_answer_id = Some(answer_id);
+            self.release_open_question_slot(&question.creator);
+            self.return_answer_stakes(question_id, false);
+        }
+        self.questions(&question_id).set(&question);
+        self.pay_answerer(&answer.creator, &milestone.amount);
+
+        self.event_milestone_approved(&question_id, milestone_id, &answer_id, &milestone.amount);
+    }
+
+    #[view(getMilestones)]
+    fn get_milestones(&self, question_id: u64) -> MultiValueEncoded<Milestone<Self::Api>> {
+        self.question_milestones(&question_id).iter().collect()
+    }
+
+    #[endpoint(refundQuestion)]
+    fn refund_question(&self, question_id: u64) {
+        let caller = self.blockchain().get_caller();
+        self.do_refund_question(question_id, Some(caller));
+    }
+
+    // New: Internal refund logic, can be called by anyone or system
+    fn do_refund_question(&self, question_id: u64, caller_opt: Option<ManagedAddress>) {
+        let timestamp = self.blockchain().get_block_timestamp();
+        let mut question = self.questions(&question_id).get();
+        let creator = question.creator.clone();
+        // Batch refunds (no explicit caller) always credit pending balances instead of sending
+        let batch = caller_opt.is_none();
+        let caller = caller_opt.unwrap_or_else(|| creator.clone());
+
+        require!(caller == creator, "Only creator can refund");
+        require!(timestamp >= self.closing_time(&question), "Deadline not reached");
+        require!(self.is_refundable_status(&question.status), "Question already handled");
+
+        question.status = QuestionStatus::Expired;
+        self.questions(&question_id).set(&question);
+        self.release_open_question_slot(&creator);
+        self.refund_question_funds(&question, batch);
+        self.return_answer_stakes(question_id, batch);
+
+        self.event_tokens_refunded(&question_id, &creator);
+    }
+
+    // New: Expire all questions past deadline (can be called by anyone)
+    #[endpoint(expireQuestions)]
+    fn expire_questions(&self) {
+        let now = self.blockchain().get_block_timestamp();
+        let total_questions = self.question_id().get();
+        for qid in 1..=total_questions {
+            let question = self.questions(&qid).get();
+            if self.is_refundable_status(&question.status) && now >= self.closing_time(&question) {
+                // Refund to creator
+                self.do_refund_question(qid, None);
+            }
+        }
+    }
+
+    #[endpoint(addAllowedAnswerers)]
+    fn add_allowed_answerers(&self, question_id: u64, answerers: MultiValueEncoded<ManagedAddress>) {
+        self.require_allowlist_editable(question_id);
+        let mut allowlist = self.allowed_answerers(&question_id);
+        for answerer in answerers {
+            allowlist.insert(answerer);
+        }
+        self.event_allowlist_updated(&question_id, allowlist.len() as u32);
+    }
+
+    #[endpoint(removeAllowedAnswerers)]
+    fn remove_allowed_answerers(&self, question_id: u64, answerers: MultiValueEncoded<ManagedAddress>) {
+        self.require_allowlist_editable(question_id);
+        let mut allowlist = self.allowed_answerers(&question_id);
+        for answerer in answerers {
+            allowlist.swap_remove(&answerer);
+        }
+        self.event_allowlist_updated(&question_id, allowlist.len() as u32);
+    }
+
+    fn require_allowlist_editable(&self, question_id: u64) {
+        let question = self.questions(&question_id).get();
+        require!(self.blockchain().get_caller() == question.creator, "Only creator can edit allowlist");
+        require!(self.invite_only(&question_id).get(), "Question is not invite-only");
+        require!(
+            question.status == QuestionStatus::Created || question.status == QuestionStatus::Answered,
+            "Question is closed"
+        );
+    }
+
+    #[view(canAnswer)]
+    fn can_answer(&self, question_id: u64, address: ManagedAddress) -> bool {
+        let mapper = self.questions(&question_id);
+        if mapper.is_empty() {
+            return false;
+        }
+        let question = mapper.get();
+        (question.status == QuestionStatus::Created || question.status == QuestionStatus::Answered)
+            && self.blockchain().get_block_timestamp() < question.deadline
+            && address != question.creator
+            && !self.denylist().contains(&address)
+            && (!self.invite_only(&question_id).get() || self.allowed_answerers(&question_id).contains(&address))
+    }
+
+    #[view(getAllowedAnswerers)]
+    fn get_allowed_answerers(&self, question_id: u64) -> MultiValueEncoded<ManagedAddress> {
+        self.allowed_answerers(&question_id).iter().collect()
+    }
+
+    // Solvers first commit sha256(solver | preimage) and reveal it in a later block, so a
+    // preimage seen in a pending reveal can't be replayed by someone else
+    #[endpoint(commitPuzzleSolution)]
+    fn commit_puzzle_solution(&self, question_id: u64, commitment: ManagedByteArray<32>) {
+        require!(!self.is_paused().get(), "Contract is paused");
+        let caller = self.blockchain().get_caller();
+        let question = self.questions(&question_id).get();
+        require!(!self.puzzle_hash(&question_id).is_empty(), "Question is not a puzzle");
+        require!(question.status == QuestionStatus::Created, "Question is closed");
+        require!(self.blockchain().get_block_timestamp() < question.deadline, "Question deadline passed");
+        require!(caller != question.creator, "Creator cannot answer own question");
+        self.require_not_denylisted(&caller);
+        require!(
+            !self.invite_only(&question_id).get() || self.allowed_answerers(&question_id).contains(&caller),
+            "Not invited to answer"
+        );
+
+        self.puzzle_commitment(&question_id, &caller).set(&commitment);
+        self.puzzle_commit_nonce(&question_id, &caller).set(self.blockchain().get_block_nonce());
+        self.event_puzzle_solution_committed(&question_id, &caller);
+    }
+
+    #[endpoint(solvePuzzle)]
+    fn solve_puzzle(&self, question_id: u64, preimage: ManagedBuffer) -> u64 {
+        require!(!self.is_paused().get(), "Contract is paused");
+        let caller = self.blockchain().get_caller();
+        let question = self.questions(&question_id).get();
+        require!(!self.puzzle_hash(&question_id).is_empty(), "Question is not a puzzle");
+        require!(question.status == QuestionStatus::Created, "Question is closed");
+        require!(self.blockchain().get_block_timestamp() < question.deadline, "Question deadline passed");
+
+        let commitment = self.puzzle_commitment(&question_id, &caller);
+        require!(!commitment.is_empty(), "No committed solution");
+        require!(
+            self.blockchain().get_block_nonce() > self.puzzle_commit_nonce(&question_id, &caller).get(),
+            "Reveal must happen after the commit block"
+        );
+        require!(
+            self.compute_puzzle_commitment(caller.clone(), preimage.clone()) == commitment.get(),
+            "Commitment mismatch"
+        );
+        require!(self.crypto().sha256(&preimage) == self.puzzle_hash(&question_id).get(), "Wrong solution");
+
+        let aid = self.store_answer(question_id, &caller, ManagedBuffer::from(b"Puzzle solution"), preimage);
+        let answer = self.answers(&aid).get();
+        self.finalize_approval(question, answer);
+        self.event_puzzle_solved(&question_id, &caller);
+        aid
+    }
+
+    #[view(computePuzzleCommitment)]
+    fn compute_puzzle_commitment(&self, solver: ManagedAddress, preimage: ManagedBuffer) -> ManagedByteArray<32> {
+        let mut data = ManagedBuffer::new();
+        data.append(solver.as_managed_buffer());
+        data.append(&preimage);
+        self.crypto().sha256(&data)
+    }
+
+    #[view(getPuzzleHash)]
+    fn get_puzzle_hash(&self, question_id: u64) -> OptionalValue<ManagedByteArray<32>> {
+        let mapper = self.puzzle_hash(&question_id);
+        if mapper.is_empty() {
+            OptionalValue::None
+        } else {
+            OptionalValue::Some(mapper.get())
+        }
+    }
+
+    // Duplicates: the creator or a moderator closes a question as a copy of a canonical one. The
+    // bounty is refunded, or the creator can move their own deposit into the canonical question.
+    // Creators only get a refund this way while nobody has worked on the question yet
+    #[endpoint(closeAsDuplicate)]
+    fn close_as_duplicate(&self, question_id: u64, canonical_id: u64, move_bounty: bool) {
+        let caller = self.blockchain().get_caller();
+        let mut question = self.questions(&question_id).get();
+        require!(
+            caller == question.creator || self.moderators().contains(&caller),
+            "Only creator or moderator can close as duplicate"
+        );
+        require!(
+            question.status == QuestionStatus::Created || question.status == QuestionStatus::Answered,
+            "Question is closed"
+        );
+        require!(canonical_id != question_id, "Question cannot duplicate itself");
+        require!(!self.questions(&canonical_id).is_empty(), "Canonical question does not exist");
+        let mut canonical = self.questions(&canonical_id).get();
+        require!(canonical.status != QuestionStatus::Duplicate, "Canonical question is itself a duplicate");
+        if !move_bounty && !self.moderators().contains(&caller) {
+            require!(
+                question.status == QuestionStatus::Created && self.puzzle_hash(&question_id).is_empty(),
+                "Only moderator can refund an answered or puzzle question"
+            );
+        }
+
+        question.status = QuestionStatus::Duplicate;
+        question.duplicate_of = Some(canonical_id);
+        self.questions(&question_id).set(&question);
+        self.release_open_question_slot(&question.creator);
+
+        if move_bounty {
+            require!(caller == question.creator, "Only creator can move the bounty");
+            require!(
+                canonical.status == QuestionStatus::Created || canonical.status == QuestionStatus::Answered,
+                "Canonical question is closed"
+            );
+            require!(
+                self.blockchain().get_block_timestamp() < canonical.deadline,
+                "Canonical question deadline has passed"
+            );
+            require!(
+                self.question_milestones(&question_id).is_empty() && self.question_milestones(&canonical_id).is_empty(),
+                "Milestone bounties cannot be moved"
+            );
+
+            // Sponsor top-ups were tied to this question, so they go back to their pools
+            let returned_to_pools = self.return_sponsor_matches(question_id);
+            self.total_locked(&EgldOrEsdtTokenIdentifier::egld())
+                .update(|total| *total -= &returned_to_pools);
+            let moved = &question.locked_amount - &returned_to_pools;
+            canonical.locked_amount += &moved;
+            self.questions(&canonical_id).set(&canonical);
+            self.event_question_closed_as_duplicate(&question_id, &canonical_id, &moved);
+        } else {
+            self.refund_question_funds(&question, false);
+            self.event_tokens_refunded(&question_id, &question.creator);
+            self.event_question_closed_as_duplicate(&question_id, &canonical_id, &BigUint::zero());
+        }
+        self.return_answer_stakes(question_id, false);
+    }
+
+    // Open questions and assigned jobs that were never delivered can be refunded to the creator
+    fn is_refundable_status(&self, status: &QuestionStatus) -> bool {
+        matches!(
+            status,
+            QuestionStatus::Created
+                | QuestionStatus::Answered
+                | QuestionStatus::Assigned
+                | QuestionStatus::InProgress
+        )
+    }
+
+    // Assigned jobs: the creator hands an unanswered question to a single assignee, who accepts
+    // it and delivers before the deadline. A delivery the creator neither approves nor disputes
+    // within the review window can be released to the assignee by anyone
+    #[endpoint(assignQuestion)]
+    fn assign_question(&self, question_id: u64, assignee: ManagedAddress, review_window: u64) {
+        require!(!self.is_paused().get(), "Contract is paused");
+        let caller = self.blockchain().get_caller();
+        let mut question = self.questions(&question_id).get();
+        require!(caller == question.creator, "Only creator can assign");
+        require!(question.status == QuestionStatus::Created, "Question already has answers or is closed");
+        require!(self.blockchain().get_block_timestamp() < question.deadline, "Question deadline passed");
+        require!(self.question_milestones(&question_id).is_empty(), "Milestone questions cannot be assigned");
+        require!(self.puzzle_hash(&question_id).is_empty(), "Puzzle questions cannot be assigned");
+        require!(self.competition_schedule(&question_id).is_empty(), "Competition questions cannot be assigned");
+        require!(assignee != question.creator, "Creator cannot be the assignee");
+        self.require_not_denylisted(&assignee);
+        require!(
+            !self.invite_only(&question_id).get() || self.allowed_answerers(&question_id).contains(&assignee),
+            "Not invited to answer"
+        );
+        require!(review_window > 0, "Review window must be positive");
+        require!(review_window <= MAX_REVIEW_WINDOW, "Review window too long");
+
+        question.status = QuestionStatus::Assigned;
+        self.questions(&question_id).set(&question);
+        self.job_assignment(&question_id).set(&JobAssignment {
+            assignee: assignee.clone(),
+            review_window,
+            accepted_at: 0,
+            delivered_at: 0,
+            answer_id: None,
+        });
+
+        self.event_job_assigned(&question_id, &assignee, review_window);
+    }
+
+    #[endpoint(acceptAssignment)]
+    fn accept_assignment(&self, question_id: u64) {
+        require!(!self.is_paused().get(), "Contract is paused");
+        let caller = self.blockchain().get_caller();
+        let mut question = self.questions(&question_id).get();
+        require!(question.status == QuestionStatus::Assigned, "Question is not awaiting acceptance");
+        let mut assignment = self.job_assignment(&question_id).get();
+        require!(caller == assignment.assignee, "Only assignee can accept");
+
+        assignment.accepted_at = self.blockchain().get_block_timestamp();
+        question.status = QuestionStatus::InProgress;
+        self.questions(&question_id).set(&question);
+        self.job_assignment(&question_id).set(&assignment);
+
+        self.event_job_accepted(&question_id, &caller);
+    }
+
+    // The delivery is recorded as the assignee's answer so it can go through approveAnswer
+    #[endpoint(markDelivered)]
+    fn mark_delivered(&self, question_id: u64, title: ManagedBuffer, description: ManagedBuffer) -> u64 {
+        require!(!self.is_paused().get(), "Contract is paused");
+        let caller = self.blockchain().get_caller();
+        let timestamp = self.blockchain().get_block_timestamp();
+        let mut question = self.questions(&question_id).get();
+        require!(question.status == QuestionStatus::InProgress, "Job is not in progress");
+        require!(timestamp < question.deadline, "Question deadline passed");
+        let mut assignment = self.job_assignment(&question_id).get();
+        require!(caller == assignment.assignee, "Only assignee can deliver");
+        require!(!title.is_empty() && !description.is_empty(), "Title or description is empty");
+
+        let aid = self.store_answer(question_id, &caller, title, description);
+
+        assignment.delivered_at = timestamp;
+        assignment.answer_id = Some(aid);
+        question.status = QuestionStatus::Delivered;
+        self.questions(&question_id).set(&question);
+        self.job_assignment(&question_id).set(&assignment);
+
+        self.event_job_delivered(&question_id, &aid);
+        aid
+    }
+
+    // Records an answer that skips the open submission checks (deliveries, puzzle solutions)
+    fn store_answer(
+        &self,
+        question_id: u64,
+        creator: &ManagedAddress,
+        title: ManagedBuffer,
+        description: ManagedBuffer,
+    ) -> u64 {
+        let aid = self.answer_id().update(|id| {
+            *id += 1;
+            *id
+        });
+        let answer = Answer {
+            answer_id: aid,
+            question_id,
+            creator: creator.clone(),
+            title,
+            description,
+            created_at: self.blockchain().get_block_timestamp(),
+            votes: 0,
+            approved_by_creator: false,
+        };
+        self.answers(&aid).set(&answer);
+        self.answers_by_question(&question_id).insert(aid);
+
+        self.event_answer_submitted(&aid, &question_id, creator);
+        aid
+    }
+
+    #[endpoint(disputeDelivery)]
+    fn dispute_delivery(&self, question_id: u64) {
+        let caller = self.blockchain().get_caller();
+        let mut question = self.questions(&question_id).get();
+        require!(caller == question.creator, "Only creator can dispute");
+        require!(question.status == QuestionStatus::Delivered, "Job is not delivered");
+        let assignment = self.job_assignment(&question_id).get();
+        require!(
+            self.blockchain().get_block_timestamp() < assignment.delivered_at.saturating_add(assignment.review_window),
+            "Review window has ended"
+        );
+
+        question.status = QuestionStatus::Disputed;
+        self.questions(&question_id).set(&question);
+        self.event_job_disputed(&question_id);
+    }
+
+    #[endpoint(releaseDelivery)]
+    fn release_delivery(&self, question_id: u64) {
+        let question = self.questions(&question_id).get();
+        require!(question.status == QuestionStatus::Delivered, "Job is not delivered");
+        let assignment = self.job_assignment(&question_id).get();
+        require!(
+            self.blockchain().get_block_timestamp() >= assignment.delivered_at.saturating_add(assignment.review_window),
+            "Review window still open"
+        );
+
+        let answer = self.answers(&assignment.answer_id.unwrap_or_default()).get();
+        self.finalize_approval(question, answer);
+        self.event_job_auto_released(&question_id, &assignment.assignee);
+    }
+
+    // Moderators settle disputed jobs either way
+    #[endpoint(resolveDispute)]
+    fn resolve_dispute(&self, question_id: u64, release_to_assignee: bool) {
+        self.require_moderator();
+        let mut question = self.questions(&question_id).get();
+        require!(question.status == QuestionStatus::Disputed, "Job is not disputed");
+
+        if release_to_assignee {
+            let assignment = self.job_assignment(&question_id).get();
+            let answer = self.answers(&assignment.answer_id.unwrap_or_default()).get();
+            self.finalize_approval(question, answer);
+        } else {
+            question.status = QuestionStatus::Expired;
+            self.questions(&question_id).set(&question);
+            self.release_open_question_slot(&question.creator);
+            self.refund_question_funds(&question, false);
+            self.event_tokens_refunded(&question_id, &question.creator);
+        }
+        self.event_dispute_resolved(&question_id, release_to_assignee);
+    }
+
+    #[view(getReviewers)]
+    fn get_reviewers(&self, question_id: u64) -> MultiValue2<u32, MultiValueEncoded<ManagedAddress>> {
+        (
+            self.required_approvals(&question_id).get(),
+            self.question_reviewers(&question_id).iter().collect(),
+        ).into()
+    }
+
+    #[view(getReviewerApprovals)]
+    fn get_reviewer_approvals(&self, answer_id: u64) -> MultiValueEncoded<ManagedAddress> {
+        self.reviewer_approvals(&answer_id).iter().collect()
+    }
+
+    #[view(getJobAssignment)]
+    fn get_job_assignment(&self, question_id: u64) -> OptionalValue<JobAssignment<Self::Api>> {
+        let mapper = self.job_assignment(&question_id);
+        if mapper.is_empty() {
+            OptionalValue::None
+        } else {
+            OptionalValue::Some(mapper.get())
+        }
+    }
+
+    // Anti-spam answer stakes: set by the question creator before any answer is submitted
+    #[endpoint(setAnswerStake)]
+    fn set_answer_stake(&self, question_id: u64, amount: BigUint) {
+        let caller = self.blockchain().get_caller();
+        let question = self.questions(&question_id).get();
+        require!(caller == question.creator, "Only creator can set answer stake");
+        require!(question.status == QuestionStatus::Created, "Question already has answers or is closed");
+
+        self.answer_stake(&question_id).set(&amount);
+        self.event_answer_stake_set(&question_id, &amount);
+    }
+
+    // Slashes the answer's stake to the treasury; only possible while the question is still open
+    #[endpoint(markAnswerAsSpam)]
+    fn mark_answer_as_spam(&self, answer_id: u64) {
+        let caller = self.blockchain().get_caller();
+        let answer = self.answers(&answer_id).get();
+        let question = self.questions(&answer.question_id).get();
+        require!(
+            caller == question.creator
+                || caller == self.blockchain().get_owner_address()
+                || self.moderators().contains(&caller),
+            "Only creator or moderator can mark spam"
+        );
+        require!(question.status == QuestionStatus::Created || question.status == QuestionStatus::Answered, "Question is closed");
+        require!(!self.answer_marked_spam(&answer_id).get(), "Answer already marked as spam");
+
+        self.answer_marked_spam(&answer_id).set(true);
+        let slashed = self.answer_stake_deposit(&answer_id).take();
+        self.total_answer_stakes().update(|total| *total -= &slashed);
+        self.payout(&self.get_treasury(), &EgldOrEsdtTokenIdentifier::egld(), &slashed, false);
+
+        self.event_answer_marked_spam(&answer_id, &answer.question_id, &caller, &slashed);
+    }
+
+    // Returns the remaining answer stakes of a closed question to their answerers
+    fn return_answer_stakes(&self, question_id: u64, force_pull: bool) {
+        for aid in self.answers_by_question(&question_id).iter() {
+            let stake = self.answer_stake_deposit(&aid).take();
+            if stake > 0 {
+                self.total_answer_stakes().update(|total| *total -= &stake);
+                let answer = self.answers(&aid).get();
+                self.payout(&answer.creator, &EgldOrEsdtTokenIdentifier::egld(), &stake, force_pull);
+            }
+        }
+    }
+
+    // Pull-based payouts: recipients who opted in (or any recipient in batch paths) are credited
+    // a pending balance to claim later instead of receiving a direct transfer
+    #[endpoint(claim)]
+    fn claim(&self) {
+        let caller = self.blockchain().get_caller();
+        let mut tokens = self.pending_tokens(&caller);
+        require!(!tokens.is_empty(), "Nothing to claim");
+
+        for token in tokens.iter() {
+            let amount = self.pending_balance(&caller, &token).take();
+            self.total_unclaimed(&token).update(|total| *total -= &amount);
+            self.send().direct(&caller, &token, 0u64, &amount);
+            self.event_payout_claimed(&caller, &token, &amount);
+        }
+        tokens.clear();
+    }
+
+    #[endpoint(setPullPayouts)]
+    fn set_pull_payouts(&self, enabled: bool) {
+        let caller = self.blockchain().get_caller();
+        self.pull_payouts_enabled(&caller).set(enabled);
+    }
+
+    fn payout(
+        &self,
+        to: &ManagedAddress,
+        token: &EgldOrEsdtTokenIdentifier,
+        amount: &BigUint,
+        force_pull: bool,
+    ) {
+        if *amount == 0 {
+            return;
+        }
+
+        if force_pull || self.pull_payouts_enabled(to).get() {
+            self.pending_balance(to, token).update(|balance| *balance += amount);
+            self.total_unclaimed(token).update(|total| *total += amount);
+            self.pending_tokens(to).insert(token.clone());
+            self.event_payout_credited(to, token, amount);
+        } else {
+            self.send().direct(to, token, 0u64, amount);
+        }
+    }
+
+    // StackToken ESDT: issued once by the owner, minted as a reward on every approved answer
+    #[only_owner]
+    #[payable("EGLD")]
+    #[endpoint(issueStackToken)]
+    fn issue_stack_token(
+        &self,
+        token_display_name: ManagedBuffer,
+        token_ticker: ManagedBuffer,
+        num_decimals: usize,
+    ) {
+        let issue_cost = self.call_value().egld().clone();
+        self.stack_token().issue_and_set_all_roles(
+            issue_cost,
+            token_display_name,
+            token_ticker,
+            num_decimals,
+            Some(self.callbacks().stack_token_issue_callback()),
+        );
+    }
+
+    #[callback]
+    fn stack_token_issue_callback(&self, #[call_result] result: ManagedAsyncCallResult<TokenIdentifier>) {
+        match result {
+            ManagedAsyncCallResult::Ok(token_id) => {
+                self.stack_token().set_token_id(token_id.clone());
+                self.event_stack_token_issued(&token_id);
+            },
+            ManagedAsyncCallResult::Err(_) => {
+                // Return the issue cost to the owner and allow issuing again
+                let returned = self.call_value().egld_direct_non_strict();
+                if *returned > 0 {
+                    self.send().direct_egld(&self.blockchain().get_owner_address(), &returned);
+                }
+                self.stack_token().clear();
+            },
+        }
+    }
+
+    // The asker reward must not exceed the answerer reward
+    #[only_owner]
+    #[endpoint(setStackTokenRewards)]
+    fn set_stack_token_rewards(&self, answerer_reward: BigUint, asker_reward: BigUint) {
+        require!(asker_reward <= answerer_reward, "Asker reward exceeds answerer reward");
+        self.answerer_reward().set(&answerer_reward);
+        self.asker_reward().set(&asker_reward);
+        self.event_stack_token_rewards_updated(&answerer_reward, &asker_reward);
+    }
+
+    fn mint_stack_token_reward(&self, to: &ManagedAddress, amount: &BigUint) {
+        if *amount == 0 || !self.stack_token().get_token_state().is_set() {
+            return;
+        }
+
+        let minted = self.stack_token().mint(amount.clone());
+        self.payout(
+            to,
+            &EgldOrEsdtTokenIdentifier::esdt(minted.token_identifier),
+            &minted.amount,
+            false,
+        );
+        self.event_stack_token_rewarded(to, &minted.amount);
+    }
+
+    #[view(getStackTokenId)]
+    fn get_stack_token_id(&self) -> OptionalValue<TokenIdentifier> {
+        if self.stack_token().get_token_state().is_set() {
+            OptionalValue::Some(self.stack_token().get_token_id())
+        } else {
+            OptionalValue::None
+        }
+    }
+
+    // Returns (answerer reward, asker reward)
+    #[view(getStackTokenRewards)]
+    fn get_stack_token_rewards(&self) -> MultiValue2<BigUint, BigUint> {
+        (self.answerer_reward().get(), self.asker_reward().get()).into()
+    }
+
+    // StackToken staking: staked balance weights votes and discounts the platform fee
+    #[payable("*")]
+    #[endpoint(stake)]
+    fn stake(&self) {
+        let caller = self.blockchain().get_caller();
+        let (token_id, amount) = self.call_value().single_fungible_esdt();
+        require!(self.stack_token().get_token_state().is_set(), "StackToken not issued");
+        require!(*token_id == self.stack_token().get_token_id(), "Only StackToken can be staked");
+
+        self.staked_amount(&caller).update(|staked| *staked += &*amount);
+        self.total_staked().update(|total| *total += &*amount);
+        self.event_tokens_staked(&caller, &amount);
+    }
+
+    // Moves the amount into unbonding; adding to an existing unbonding restarts its period
+    #[endpoint(unstake)]
+    fn unstake(&self, amount: BigUint) {
+        let caller = self.blockchain().get_caller();
+        let staked = self.staked_amount(&caller).get();
+        require!(amount > 0 && amount <= staked, "Invalid unstake amount");
+        // Stake that has voted stays put until the vote closes, so it cannot be moved and counted again
+        require!(
+            self.blockchain().get_block_timestamp() >= self.stake_locked_until(&caller).get(),
+            "Stake locked by active vote"
+        );
+
+        self.staked_amount(&caller).set(&(staked - &amount));
+        self.total_staked().update(|total| *total -= &amount);
+        self.unbonding_amount(&caller).update(|unbonding| *unbonding += &amount);
+        self.total_unbonding().update(|total| *total += &amount);
+        let unbonding_until = self.blockchain().get_block_timestamp() + self.unbonding_period().get();
+        self.unbonding_until(&caller).set(unbonding_until);
+
+        self.event_tokens_unstaked(&caller, &amount, unbonding_until);
+    }
+
+    #[endpoint(withdrawUnstaked)]
+    fn withdraw_unstaked(&self) {
+        let caller = self.blockchain().get_caller();
+        let amount = self.unbonding_amount(&caller).get();
+        require!(amount > 0, "Nothing to withdraw");
+        require!(
+            self.blockchain().get_block_timestamp() >= self.unbonding_until(&caller).get(),
+            "Unbonding period not over"
+        );
+
+        self.unbonding_amount(&caller).clear();
+        self.total_unbonding().update(|total| *total -= &amount);
+        self.unbonding_until(&caller).clear();
+        self.send().direct_esdt(&caller, &self.stack_token().get_token_id(), 0, &amount);
+        self.event_unstaked_withdrawn(&caller, &amount);
+    }
+
+    #[only_owner]
+    #[endpoint(setUnbondingPeriod)]
+    fn set_unbonding_period(&self, unbonding_period: u64) {
+        require!(unbonding_period >= MIN_UNBONDING_PERIOD, "Unbonding period too short");
+        self.unbonding_period().set(unbonding_period);
+    }
+
+    // Each full `stake_per_extra_vote` adds one vote, up to `max_vote_weight` in total; 0 disables weighting
+    #[only_owner]
+    #[endpoint(setVoteWeightParams)]
+    fn set_vote_weight_params(&self, stake_per_extra_vote: BigUint, max_vote_weight: u32) {
+        require!(max_vote_weight >= 1, "Invalid max vote weight");
+        self.stake_per_extra_vote().set(&stake_per_extra_vote);
+        self.max_vote_weight().set(max_vote_weight);
+    }
+
+    #[view(getVoteWeight)]
+    fn get_vote_weight(&self, address: ManagedAddress) -> u32 {
+        let stake_per_extra_vote = self.stake_per_extra_vote().get();
+        if stake_per_extra_vote == 0 {
+            return 1;
+        }
+
+        let extra_votes = (self.staked_amount(&address).get() / stake_per_extra_vote)
+            .to_u64()
+            .unwrap_or(u64::MAX);
+        let max_vote_weight = self.max_vote_weight().get() as u64;
+        core::cmp::min(1 + extra_votes, max_vote_weight) as u32
+    }
+
+    #[view(getPlatformFeeFor)]
+    fn get_platform_fee_for(&self, recipient: ManagedAddress, amount: BigUint) -> BigUint {
+        let mut fee_bps = self.platform_fee_bps().get();
+        let min_stake = self.fee_discount_min_stake().get();
+        if min_stake > 0 && self.staked_amount(&recipient).get() >= min_stake {
+            fee_bps -= fee_bps * self.fee_discount_bps().get() / BPS_DENOMINATOR;
+        }
+        amount * fee_bps / BPS_DENOMINATOR
+    }
+
+    #[view(getStakedAmount)]
+    fn get_staked_amount(&self, address: ManagedAddress) -> BigUint {
+        self.staked_amount(&address).get()
+    }
+
+    #[view(getStakeLockedUntil)]
+    fn get_stake_locked_until(&self, address: ManagedAddress) -> u64 {
+        self.stake_locked_until(&address).get()
+    }
+
+    // Returns (unbonding amount, withdrawable from timestamp)
+    #[view(getUnbonding)]
+    fn get_unbonding(&self, address: ManagedAddress) -> MultiValue2<BigUint, u64> {
+        (self.unbonding_amount(&address).get(), self.unbonding_until(&address).get()).into()
+    }
+
+    #[view(getTotalStaked)]
+    fn get_total_staked(&self) -> BigUint {
+        self.total_staked().get()
+    }
+
+    #[view(getPlatformFee)]
+    fn get_platform_fee(&self) -> u64 {
+        self.platform_fee_bps().get()
+    }
+
+    // Governance: stakers or high-reputation users propose parameter changes, stakers vote with
+    // their staked balance, and passed proposals become executable once the timelock ends
+    #[endpoint(proposeParameterChange)]
+    fn propose_parameter_change(&self, parameter: GovernanceParameter, value: BigUint) -> u64 {
+        let caller = self.blockchain().get_caller();
+        let min_stake = self.proposal_min_stake().get();
+        let min_reputation = self.proposal_min_reputation().get();
+        require!(
+            (min_stake > 0 && self.staked_amount(&caller).get() >= min_stake)
+                || (min_reputation > 0 && self.approved_answer_count(&caller).get() >= min_reputation),
+            "Not allowed to propose"
+        );
+        self.validate_parameter_value(&parameter, &value);
+
+        let now = self.blockchain().get_block_timestamp();
+        let proposal_id = self.proposal_id().update(|id| {
+            *id += 1;
+            *id
+        });
+        let proposal = Proposal {
+            proposal_id,
+            proposer: caller.clone(),
+            parameter,
+            value,
+            created_at: now,
+            voting_ends_at: now + self.voting_period().get(),
+            votes_for: BigUint::zero(),
+            votes_against: BigUint::zero(),
+            executed: false,
+        };
+        self.proposals(&proposal_id).set(&proposal);
+
+        self.event_proposal_created(&proposal_id, &caller, &proposal.parameter, &proposal.value);
+        proposal_id
+    }
+
+    #[endpoint(voteProposal)]
+    fn vote_proposal(&self, proposal_id: u64, support: bool) {
+        let caller = self.blockchain().get_caller();
+        let mut proposal = self.proposals(&proposal_id).get();
+        require!(self.blockchain().get_block_timestamp() < proposal.voting_ends_at, "Voting period ended");
+
+        let weight = self.staked_amount(&caller).get();
+        require!(weight > 0, "No voting power");
+        require!(self.proposal_voters(&proposal_id).insert(caller.clone()), "Already voted");
+        self.stake_locked_until(&caller)
+            .update(|locked_until| *locked_until = (*locked_until).max(proposal.voting_ends_at));
+
+        if support {
+            proposal.votes_for += &weight;
+        } else {
+            proposal.votes_against += &weight;
+        }
+        self.proposals(&proposal_id).set(&proposal);
+
+        self.event_proposal_voted(&proposal_id, &caller, support, &weight);
+    }
+
+    #[endpoint(executeProposal)]
+    fn execute_proposal(&self, proposal_id: u64) {
+        require!(
+            self.get_proposal_status(proposal_id) == ProposalStatus::Executable,
+            "Proposal not executable"
+        );
+
+        let mut proposal = self.proposals(&proposal_id).get();
+        // Bounds may have changed since the proposal was created
+        self.validate_parameter_value(&proposal.parameter, &proposal.value);
+        match proposal.parameter {
+            GovernanceParameter::MinBounty => self.min_bounty().set(&proposal.value),
+            GovernanceParameter::PlatformFee => self.platform_fee_bps().set(proposal.value.to_u64().unwrap()),
+            GovernanceParameter::MinDeadlineDuration => {
+                self.min_deadline_duration().set(proposal.value.to_u64().unwrap())
+            },
+            GovernanceParameter::MaxDeadlineDuration => {
+                self.max_deadline_duration().set(proposal.value.to_u64().unwrap())
+            },
+        }
+
+        proposal.executed = true;
+        self.proposals(&proposal_id).set(&proposal);
+        self.event_proposal_executed(&proposal_id);
+    }
+
+    #[only_owner]
+    #[endpoint(setGovernanceParams)]
+    fn set_governance_params(
+        &self,
+        voting_period: u64,
+        timelock: u64,
+        quorum: BigUint,
+        proposal_min_stake: BigUint,
+        proposal_min_reputation: u64,
+    ) {
+        // Parameter changes must stay visible for at least as long as a timelocked admin action
+        require!(voting_period >= MIN_VOTING_PERIOD, "Voting period too short");
+        require!(timelock >= MIN_GOVERNANCE_TIMELOCK, "Governance timelock too short");
+        require!(quorum > 0, "Invalid quorum");
+        self.voting_period().set(voting_period);
+        self.governance_timelock().set(timelock);
+        self.quorum().set(&quorum);
+        self.proposal_min_stake().set(&proposal_min_stake);
+        self.proposal_min_reputation().set(proposal_min_reputation);
+    }
+
+    fn validate_parameter_value(&self, parameter: &GovernanceParameter, value: &BigUint) {
+        match parameter {
+            GovernanceParameter::MinBounty => require!(*value > 0, "Invalid minimum bounty"),
+            GovernanceParameter::PlatformFee => {
+                require!(*value <= MAX_PLATFORM_FEE_BPS, "Platform fee too high")
+            },
+            GovernanceParameter::MinDeadlineDuration => {
+                let max = self.max_deadline_duration().get();
+                require!(*value <= u64::MAX, "Invalid deadline duration");
+                require!(max == 0 || *value <= max, "Min deadline duration above max");
+            },
+            GovernanceParameter::MaxDeadlineDuration => {
+                require!(*value <= u64::MAX, "Invalid deadline duration");
+                require!(
+                    *value == 0 || *value >= self.min_deadline_duration().get(),
+                    "Max deadline duration below min"
+                );
+            },
+        }
+    }
+
+    // Deadline bounds are durations from now; a bound of 0 is not enforced
+    fn require_deadline_within_bounds(&self, deadline: u64) {
+        let duration = deadline - self.blockchain().get_block_timestamp();
+        let min_duration = self.min_deadline_duration().get();
+        let max_duration = self.max_deadline_duration().get();
+        require!(duration >= min_duration, "Deadline too soon");
+        require!(max_duration == 0 || duration <= max_duration, "Deadline too far");
+    }
+
+    #[view(getMinBounty)]
+    fn get_min_bounty(&self) -> BigUint {
+        if self.min_bounty().is_empty() {
+            BigUint::from(MIN_EGLD_LOCKED)
+        } else {
+            self.min_bounty().get()
+        }
+    }
+
+    // Returns (min deadline duration, max deadline duration)
+    #[view(getDeadlineBounds)]
+    fn get_deadline_bounds(&self) -> MultiValue2<u64, u64> {
+        (self.min_deadline_duration().get(), self.max_deadline_duration().get()).into()
+    }
+
+    #[view(getProposal)]
+    fn get_proposal(&self, proposal_id: u64) -> OptionalValue<Proposal<Self::Api>> {
+        let mapper = self.proposals(&proposal_id);
+        if mapper.is_empty() {
+            OptionalValue::None
+        } else {
+            OptionalValue::Some(mapper.get())
+        }
+    }
+
+    #[view(getProposalStatus)]
+    fn get_proposal_status(&self, proposal_id: u64) -> ProposalStatus {
+        let proposal = self.proposals(&proposal_id).get();
+        let now = self.blockchain().get_block_timestamp();
+        if proposal.executed {
+            return ProposalStatus::Executed;
+        }
+        if now < proposal.voting_ends_at {
+            return ProposalStatus::Voting;
+        }
+
+        let total_votes = &proposal.votes_for + &proposal.votes_against;
+        if proposal.votes_for <= proposal.votes_against || total_votes < self.quorum().get() {
+            return ProposalStatus::Defeated;
+        }
+        if now < proposal.voting_ends_at + self.governance_timelock().get() {
+            return ProposalStatus::Timelocked;
+        }
+        ProposalStatus::Executable
+    }
+
+    // Proposals still being voted on or passed and awaiting execution
+    #[view(getActiveProposals)]
+    fn get_active_proposals(&self) -> MultiValueEncoded<Proposal<Self::Api>> {
+        let mut result = MultiValueEncoded::new();
+        for proposal_id in 1..=self.proposal_id().get() {
+            let status = self.get_proposal_status(proposal_id);
+            if status == ProposalStatus::Voting
+                || status == ProposalStatus::Timelocked
+                || status == ProposalStatus::Executable
+            {
+                result.push(self.proposals(&proposal_id).get());
+            }
+        }
+        result
+    }
+
+    // Returns (votes for, votes against)
+    #[view(getProposalVotes)]
+    fn get_proposal_votes(&self, proposal_id: u64) -> MultiValue2<BigUint, BigUint> {
+        let proposal = self.proposals(&proposal_id).get();
+        (proposal.votes_for, proposal.votes_against).into()
+    }
+
+    // Achievement badges: a registry of criteria minted as NFTs, at most once per address
+    #[only_owner]
+    #[payable("EGLD")]
+    #[endpoint(issueBadgeCollection)]
+    fn issue_badge_collection(&self, token_display_name: ManagedBuffer, token_ticker: ManagedBuffer) {
+        let issue_cost = self.call_value().egld().clone();
+        self.badge_nft().issue_and_set_all_roles(
+            EsdtTokenType::NonFungible,
+            issue_cost,
+            token_display_name,
+            token_ticker,
+            0,
+            Some(self.callbacks().badge_collection_issue_callback()),
+        );
+    }
+
+    #[callback]
+    fn badge_collection_issue_callback(&self, #[call_result] result: ManagedAsyncCallResult<TokenIdentifier>) {
+        match result {
+            ManagedAsyncCallResult::Ok(token_id) => {
+                self.badge_nft().set_token_id(token_id.clone());
+                self.event_badge_collection_issued(&token_id);
+            },
+            ManagedAsyncCallResult::Err(_) => {
+                let returned = self.call_value().egld_direct_non_strict();
+                if *returned > 0 {
+                    self.send().direct_egld(&self.blockchain().get_owner_address(), &returned);
+                }
+                self.badge_nft().clear();
+            },
+        }
+    }
+
+    #[only_owner]
+    #[endpoint(addBadge)]
+    fn add_badge(
+        &self,
+        name: ManagedBuffer,
+        criterion: BadgeCriterion,
+        threshold: u64,
+        uri: ManagedBuffer,
+    ) -> u64 {
+        require!(!name.is_empty(), "Badge name is empty");
+        require!(criterion == BadgeCriterion::Manual || threshold > 0, "Invalid badge threshold");
+
+        let badge_id = self.badge_id().update(|id| {
+            *id += 1;
+            *id
+        });
+        let badge = Badge {
+            badge_id,
+            name,
+            criterion,
+            threshold,
+            uri,
+        };
+        self.badges(&badge_id).set(&badge);
+        self.event_badge_added(&badge_id, &badge.name);
+
+        badge_id
+    }
+
+    // Used for criteria that can't be computed on-chain, e.g. top voter of the month
+    #[only_owner]
+    #[endpoint(awardBadge)]
+    fn award_badge(&self, badge_id: u64, recipient: ManagedAddress) {
+        require!(!self.badges(&badge_id).is_empty(), "Badge does not exist");
+        require!(self.badge_nft().get_token_state().is_set(), "Badge collection not issued");
+        require!(!self.user_badges(&recipient).contains(&badge_id), "Badge already awarded");
+
+        let badge = self.badges(&badge_id).get();
+        self.mint_badge(&recipient, &badge);
+    }
+
+    // Mints every badge of this criterion whose threshold the new value reaches
+    fn check_badges(&self, user: &ManagedAddress, criterion: BadgeCriterion, value: u64) {
+        if !self.badge_nft().get_token_state().is_set() {
+            return;
+        }
+
+        for badge_id in 1..=self.badge_id().get() {
+            let badge = self.badges(&badge_id).get();
+            if badge.criterion == criterion
+                && value >= badge.threshold
+                && !self.user_badges(user).contains(&badge_id)
+            {
+                self.mint_badge(user, &badge);
+            }
+        }
+    }
+
+    fn mint_badge(&self, recipient: &ManagedAddress, badge: &Badge<Self::Api>) {
+        let token_id = self.badge_nft().get_token_id();
+        let mut uris = ManagedVec::new();
+        if !badge.uri.is_empty() {
+            uris.push(badge.uri.clone());
+        }
+
+        let nonce = self.send().esdt_nft_create(
+            &token_id,
+            &BigUint::from(1u64),
+            &badge.name,
+            &BigUint::zero(),
+            &ManagedBuffer::new(),
+            &badge.badge_id,
+            &uris,
+        );
+        // Contracts and pull-payout users claim their badges, so a recipient that can't take the
+        // NFT never blocks the approval or vote that earned it
+        if self.pull_payouts_enabled(recipient).get() || self.blockchain().is_smart_contract(recipient) {
+            self.pending_badge_nonces(recipient).insert(nonce);
+        } else {
+            self.send().direct_esdt(recipient, &token_id, nonce, &BigUint::from(1u64));
+        }
+
+        self.user_badges(recipient).insert(badge.badge_id);
+        self.event_badge_awarded(&badge.badge_id, recipient, nonce);
+    }
+
+    #[endpoint(claimBadges)]
+    fn claim_badges(&self) {
+        let caller = self.blockchain().get_caller();
+        let mut nonces = self.pending_badge_nonces(&caller);
+        require!(!nonces.is_empty(), "No badges to claim");
+
+        let token_id = self.badge_nft().get_token_id();
+        for nonce in nonces.iter() {
+            self.send().direct_esdt(&caller, &token_id, nonce, &BigUint::from(1u64));
+            self.event_badge_claimed(&caller, nonce);
+        }
+        nonces.clear();
+    }
+
+    #[view(getPendingBadges)]
+    fn get_pending_badges(&self, address: ManagedAddress) -> MultiValueEncoded<u64> {
+        self.pending_badge_nonces(&address).iter().collect()
+    }
+
+    #[view(getBadges)]
+    fn get_badges(&self, address: ManagedAddress) -> MultiValueEncoded<Badge<Self::Api>> {
+        self.user_badges(&address)
+            .iter()
+            .map(|badge_id| self.badges(&badge_id).get())
+            .collect()
+    }
+
+    #[view(getBadgeRegistry)]
+    fn get_badge_registry(&self) -> MultiValueEncoded<Badge<Self::Api>> {
+        let mut result = MultiValueEncoded::new();
+        for badge_id in 1..=self.badge_id().get() {
+            result.push(self.badges(&badge_id).get());
+        }
+        result
+    }
+
+    // Returns (approved answers, votes received, votes cast)
+    #[view(getContributionStats)]
+    fn get_contribution_stats(&self, address: ManagedAddress) -> MultiValue3<u64, u64, u64> {
+        (
+            self.approved_answer_count(&address).get(),
+            self.votes_received(&address).get(),
+            self.votes_cast(&address).get(),
+        ).into()
+    }
+
+    #[view(getClaimable)]
+    fn get_claimable(
+        &self,
+        address: ManagedAddress,
+    ) -> MultiValueEncoded<MultiValue2<EgldOrEsdtTokenIdentifier, BigUint>> {
+        let mut result = MultiValueEncoded::new();
+        for token in self.pending_tokens(&address).iter() {
+            let amount = self.pending_balance(&address, &token).get();
+            result.push((token, amount).into());
+        }
+        result
+    }
+
+    #[view(isPullPayoutsEnabled)]
+    fn is_pull_payouts_enabled(&self, address: ManagedAddress) -> bool {
+        self.pull_payouts_enabled(&address).get()
+    }
+
+    #[view(getAnswerStake)]
+    fn get_answer_stake(&self, question_id: u64) -> BigUint {
+        self.answer_stake(&question_id).get()
+    }
+
+    #[view(getAnswerStakeDeposit)]
+    fn get_answer_stake_deposit(&self, answer_id: u64) -> BigUint {
+        self.answer_stake_deposit(&answer_id).get()
+    }
+
+    #[view(isAnswerSpam)]
+    fn is_answer_spam(&self, answer_id: u64) -> bool {
+        self.answer_marked_spam(&answer_id).get()
+    }
+
+    #[view(getRevealPeriod)]
+    fn get_reveal_period(&self, question_id: u64) -> u64 {
+        self.reveal_period(&question_id).get()
+    }
+
+    // Commitment = sha256(answerer | len(title) | title | len(description) | description | salt)
+    #[view(computeAnswerCommitment)]
+    fn compute_answer_commitment(
+        &self,
+        answerer: ManagedAddress,
+        title: ManagedBuffer,
+        description: ManagedBuffer,
+        salt: ManagedBuffer,
+    ) -> ManagedByteArray<32> {
+        let mut data = ManagedBuffer::new();
+        data.append(answerer.as_managed_buffer());
+        data.append_u32_be(title.len() as u32);
+        data.append(&title);
+        data.append_u32_be(description.len() as u32);
+        data.append(&description);
+        data.append(&salt);
+        self.crypto().sha256(&data)
+    }
+
+    #[view(isAnswerRevealed)]
+    fn is_answer_revealed(&self, answer_id: u64) -> bool {
+        self.answer_commitment(&answer_id).is_empty()
+    }
+
+    #[view(computeContentHash)]
+    fn compute_content_hash(&self, title: ManagedBuffer, description: ManagedBuffer) -> ManagedByteArray<32> {
+        self.content_hash(&title, &description)
+    }
+
+    #[view(getAnswerContentHash)]
+    fn get_answer_content_hash(&self, answer_id: u64) -> OptionalValue<ManagedByteArray<32>> {
+        let mapper = self.answer_content_hash(&answer_id);
+        if mapper.is_empty() {
+            OptionalValue::None
+        } else {
+            OptionalValue::Some(mapper.get())
+        }
+    }
+
+    // Returns the earliest answer with this content: (answer_id, author, created_at)
+    #[view(getContentHashFirstAuthor)]
+    fn get_content_hash_first_author(
+        &self,
+        content_hash: ManagedByteArray<32>,
+    ) -> OptionalValue<MultiValue3<u64, ManagedAddress, u64>> {
+        let mapper = self.content_first_answer(&content_hash);
+        if mapper.is_empty() {
+            return OptionalValue::None;
+        }
+
+        let answer = self.answers(&mapper.get()).get();
+        OptionalValue::Some((answer.answer_id, answer.creator, answer.created_at).into())
+    }
+
+    // Slashed funds go to the configured treasury, or to the contract owner if none is set
+    #[view(getTreasury)]
+    fn get_treasury(&self) -> ManagedAddress {
+        if self.treasury().is_empty() {
+            self.blockchain().get_owner_address()
+        } else {
+            self.treasury().get()
+        }
+    }
+
+    #[view(getAllOpenQuestions)]
+    fn get_all_open_questions(&self) -> MultiValueEncoded<Question<Self::Api>> {
+        let now = self.blockchain().get_block_timestamp();
+        let mut results = MultiValueEncoded::new();
+        let total_questions = self.question_id().get();
+        
+        for qid in 1..=total_questions {
+            let question = self.questions(&qid).get();
+            if (question.status == QuestionStatus::Created || question.status == QuestionStatus::Answered) && question.deadline > now {
+                results.push(question);
+            }
+        }
+        
+        results
+    }
+
+    #[view(getQuestionDetails)]
+    fn get_question_details(&self, question_id: u64) -> OptionalValue<Question<Self::Api>> {
+        let mapper = self.questions(&question_id);
+        if mapper.is_empty() {
+            OptionalValue::None
+        } else {
+            OptionalValue::Some(mapper.get())
+        }
+    }
+
+    #[view(getAnswersForQuestion)]
+    fn get_answers_for_question(&self, question_id: u64) -> MultiValueEncoded<Answer<Self::Api>> {
+        let answer_ids = self.answers_by_question(&question_id);
+        answer_ids
+            .iter()
+            .filter_map(|aid| {
+                let mapper = self.answers(&aid);
+                // Unrevealed commit-reveal answers stay hidden
+                if mapper.is_empty() || !self.answer_commitment(&aid).is_empty() {
+                    None
+                } else {
+                    Some(mapper.get())
+                }
+            })
+            .collect()
+    }
+
+    // Helper function to check if a question should be marked as expired
+    fn check_and_update_question_status(&self, question_id: u64) -> QuestionStatus {
+        let now = self.blockchain().get_block_timestamp();
+        let question = self.questions(&question_id).get();
+        
+        // If deadline passed and question is not closed, it's expired
+        if now >= self.closing_time(&question) && 
+           (question.status == QuestionStatus::Created || question.status == QuestionStatus::Answered) {
+            // Don't automatically change to expired - let creator decide to refund
             // Just return what the status should be for display purposes
             return QuestionStatus::Expired;
         }
@@ -281,6 +2669,96 @@ pub trait StackTokenContract {
         question.status
     }
 
+    // Questions in commit-reveal mode stay open for reveals and approval until the reveal window ends
+    fn closing_time(&self, question: &Question<Self::Api>) -> u64 {
+        question.deadline.saturating_add(self.reveal_period(&question.question_id).get())
+    }
+
+    // Rejects exact duplicates within a question and tracks the earliest answer for each content hash
+    fn register_answer_content(&self, answer: &Answer<Self::Api>) {
+        let hash = self.content_hash(&answer.title, &answer.description);
+        require!(
+            self.question_content_hashes(&answer.question_id).insert(hash.clone()),
+            "Duplicate answer"
+        );
+        self.answer_content_hash(&answer.answer_id).set(&hash);
+
+        let first_answer = self.content_first_answer(&hash);
+        if first_answer.is_empty() || self.answers(&first_answer.get()).get().created_at > answer.created_at {
+            first_answer.set(answer.answer_id);
+        }
+    }
+
+    fn content_hash(&self, title: &ManagedBuffer, description: &ManagedBuffer) -> ManagedByteArray<32> {
+        let title = self.normalize_content(title);
+        let description = self.normalize_content(description);
+        let mut data = ManagedBuffer::new();
+        data.append_u32_be(title.len() as u32);
+        data.append(&title);
+        data.append_u32_be(description.len() as u32);
+        data.append(&description);
+        self.crypto().sha256(&data)
+    }
+
+    // Lowercases ASCII letters, trims and collapses whitespace runs into a single space
+    fn normalize_content(&self, content: &ManagedBuffer) -> ManagedBuffer {
+        let mut normalized = ManagedBuffer::new();
+        let mut input = [0u8; NORMALIZE_CHUNK_SIZE];
+        let mut output = [0u8; NORMALIZE_CHUNK_SIZE * 2];
+        let mut pending_space = false;
+        let mut started = false;
+        let len = content.len();
+        let mut offset = 0;
+
+        while offset < len {
+            let size = core::cmp::min(NORMALIZE_CHUNK_SIZE, len - offset);
+            let _ = content.load_slice(offset, &mut input[..size]);
+            let mut out_len = 0;
+            for &byte in &input[..size] {
+                if byte.is_ascii_whitespace() {
+                    pending_space = started;
+                    continue;
+                }
+                if pending_space {
+                    output[out_len] = b' ';
+                    out_len += 1;
+                    pending_space = false;
+                }
+                output[out_len] = byte.to_ascii_lowercase();
+                out_len += 1;
+                started = true;
+            }
+            normalized.append_bytes(&output[..out_len]);
+            offset += size;
+        }
+
+        normalized
+    }
+
+    fn require_not_denylisted(&self, user: &ManagedAddress) {
+        require!(!self.denylist().contains(user), "Address is denylisted");
+    }
+
+    fn require_moderator(&self) {
+        let caller = self.blockchain().get_caller();
+        require!(self.moderators().contains(&caller), "Only moderator");
+    }
+
+    // Enforces the per-user cooldown between posts (questions and answers) and records the post time
+    fn apply_post_cooldown(&self, user: &ManagedAddress) {
+        let now = self.blockchain().get_block_timestamp();
+        let cooldown = self.post_cooldown().get();
+        let last_post = self.last_post_timestamp(user);
+        if cooldown > 0 && !last_post.is_empty() {
+            require!(now >= last_post.get() + cooldown, "Post cooldown active");
+        }
+        last_post.set(now);
+    }
+
+    fn release_open_question_slot(&self, user: &ManagedAddress) {
+        self.open_question_count(user).update(|count| *count = count.saturating_sub(1));
+    }
+
     #[storage_mapper("questions")]
     fn questions(&self, question_id: &u64) -> SingleValueMapper<Question<Self::Api>>;
 
@@ -297,38 +2775,301 @@ pub trait StackTokenContract {
         data: &QuestionCreatedEventData<Self::Api>,
     );
 
-    #[storage_mapper("answers")]
-    fn answers(&self, answer_id: &u64) -> SingleValueMapper<Answer<Self::Api>>;
+    #[storage_mapper("answers")]
+    fn answers(&self, answer_id: &u64) -> SingleValueMapper<Answer<Self::Api>>;
+
+    #[storage_mapper("answers_by_question")]
+    fn answers_by_question(&self, qid: &u64) -> UnorderedSetMapper<u64>;
+
+    #[storage_mapper("user_questions")]
+    fn user_questions(&self, user: &ManagedAddress) -> UnorderedSetMapper<u64>;
+
+    #[storage_mapper("answer_stake")]
+    fn answer_stake(&self, question_id: &u64) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("answer_stake_deposit")]
+    fn answer_stake_deposit(&self, answer_id: &u64) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("answer_marked_spam")]
+    fn answer_marked_spam(&self, answer_id: &u64) -> SingleValueMapper<bool>;
+
+    #[storage_mapper("treasury")]
+    fn treasury(&self) -> SingleValueMapper<ManagedAddress>;
+
+    #[storage_mapper("pending_balance")]
+    fn pending_balance(
+        &self,
+        user: &ManagedAddress,
+        token: &EgldOrEsdtTokenIdentifier,
+    ) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("pending_tokens")]
+    fn pending_tokens(&self, user: &ManagedAddress) -> UnorderedSetMapper<EgldOrEsdtTokenIdentifier>;
+
+    #[storage_mapper("pull_payouts_enabled")]
+    fn pull_payouts_enabled(&self, user: &ManagedAddress) -> SingleValueMapper<bool>;
+
+    #[storage_mapper("stack_token")]
+    fn stack_token(&self) -> FungibleTokenMapper;
+
+    #[storage_mapper("answerer_reward")]
+    fn answerer_reward(&self) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("asker_reward")]
+    fn asker_reward(&self) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("staked_amount")]
+    fn staked_amount(&self, user: &ManagedAddress) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("total_staked")]
+    fn total_staked(&self) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("unbonding_amount")]
+    fn unbonding_amount(&self, user: &ManagedAddress) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("total_unbonding")]
+    fn total_unbonding(&self) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("unbonding_until")]
+    fn unbonding_until(&self, user: &ManagedAddress) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("stake_locked_until")]
+    fn stake_locked_until(&self, user: &ManagedAddress) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("unbonding_period")]
+    fn unbonding_period(&self) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("stake_per_extra_vote")]
+    fn stake_per_extra_vote(&self) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("max_vote_weight")]
+    fn max_vote_weight(&self) -> SingleValueMapper<u32>;
+
+    #[storage_mapper("platform_fee_bps")]
+    fn platform_fee_bps(&self) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("fee_discount_min_stake")]
+    fn fee_discount_min_stake(&self) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("fee_discount_bps")]
+    fn fee_discount_bps(&self) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("min_bounty")]
+    fn min_bounty(&self) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("min_deadline_duration")]
+    fn min_deadline_duration(&self) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("max_deadline_duration")]
+    fn max_deadline_duration(&self) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("proposal_id")]
+    fn proposal_id(&self) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("proposals")]
+    fn proposals(&self, proposal_id: &u64) -> SingleValueMapper<Proposal<Self::Api>>;
+
+    #[storage_mapper("proposal_voters")]
+    fn proposal_voters(&self, proposal_id: &u64) -> UnorderedSetMapper<ManagedAddress>;
+
+    #[storage_mapper("voting_period")]
+    fn voting_period(&self) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("governance_timelock")]
+    fn governance_timelock(&self) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("quorum")]
+    fn quorum(&self) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("proposal_min_stake")]
+    fn proposal_min_stake(&self) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("proposal_min_reputation")]
+    fn proposal_min_reputation(&self) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("answer_voters")]
+    fn answer_voters(&self, answer_id: &u64) -> UnorderedSetMapper<ManagedAddress>;
+
+    #[storage_mapper("approved_answer_count")]
+    fn approved_answer_count(&self, user: &ManagedAddress) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("votes_received")]
+    fn votes_received(&self, user: &ManagedAddress) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("votes_cast")]
+    fn votes_cast(&self, user: &ManagedAddress) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("badge_nft")]
+    fn badge_nft(&self) -> NonFungibleTokenMapper;
+
+    #[storage_mapper("badge_id")]
+    fn badge_id(&self) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("badges")]
+    fn badges(&self, badge_id: &u64) -> SingleValueMapper<Badge<Self::Api>>;
+
+    #[storage_mapper("user_badges")]
+    fn user_badges(&self, user: &ManagedAddress) -> UnorderedSetMapper<u64>;
+
+    #[storage_mapper("pending_badge_nonces")]
+    fn pending_badge_nonces(&self, user: &ManagedAddress) -> UnorderedSetMapper<u64>;
+
+    #[storage_mapper("reveal_period")]
+    fn reveal_period(&self, question_id: &u64) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("answer_commitment")]
+    fn answer_commitment(&self, answer_id: &u64) -> SingleValueMapper<ManagedByteArray<32>>;
+
+    #[storage_mapper("question_content_hashes")]
+    fn question_content_hashes(&self, question_id: &u64) -> UnorderedSetMapper<ManagedByteArray<32>>;
+
+    #[storage_mapper("answer_content_hash")]
+    fn answer_content_hash(&self, answer_id: &u64) -> SingleValueMapper<ManagedByteArray<32>>;
+
+    #[storage_mapper("content_first_answer")]
+    fn content_first_answer(&self, content_hash: &ManagedByteArray<32>) -> SingleValueMapper<u64>;
+
+    #[event("answer_submitted")]
+    fn event_answer_submitted(
+        &self,
+        #[indexed] answer_id: &u64,
+        #[indexed] question_id: &u64,
+        #[indexed] creator: &ManagedAddress,
+    );
+
+    #[event("answer_approved")]
+    fn event_answer_approved(
+        &self,
+        #[indexed] question_id: &u64,
+        #[indexed] answer_id: &u64,
+        #[indexed] rewarded_user: &ManagedAddress,
+    );
+
+    #[event("tokens_refunded")]
+    fn event_tokens_refunded(
+        &self,
+        #[indexed] question_id: &u64,
+        #[indexed] creator: &ManagedAddress,
+    );
+
+    #[event("answer_stake_set")]
+    fn event_answer_stake_set(&self, #[indexed] question_id: &u64, amount: &BigUint);
+
+    #[event("answer_marked_spam")]
+    fn event_answer_marked_spam(
+        &self,
+        #[indexed] answer_id: &u64,
+        #[indexed] question_id: &u64,
+        #[indexed] marked_by: &ManagedAddress,
+        slashed_amount: &BigUint,
+    );
+
+    #[event("commit_reveal_enabled")]
+    fn event_commit_reveal_enabled(&self, #[indexed] question_id: &u64, #[indexed] reveal_period: u64);
+
+    #[event("answer_revealed")]
+    fn event_answer_revealed(
+        &self,
+        #[indexed] answer_id: &u64,
+        #[indexed] question_id: &u64,
+        #[indexed] creator: &ManagedAddress,
+    );
+
+    #[event("payout_credited")]
+    fn event_payout_credited(
+        &self,
+        #[indexed] recipient: &ManagedAddress,
+        #[indexed] token: &EgldOrEsdtTokenIdentifier,
+        amount: &BigUint,
+    );
+
+    #[event("payout_claimed")]
+    fn event_payout_claimed(
+        &self,
+        #[indexed] recipient: &ManagedAddress,
+        #[indexed] token: &EgldOrEsdtTokenIdentifier,
+        amount: &BigUint,
+    );
+
+    #[event("stack_token_issued")]
+    fn event_stack_token_issued(&self, #[indexed] token_id: &TokenIdentifier);
+
+    #[event("stack_token_rewards_updated")]
+    fn event_stack_token_rewards_updated(
+        &self,
+        #[indexed] answerer_reward: &BigUint,
+        #[indexed] asker_reward: &BigUint,
+    );
+
+    #[event("stack_token_rewarded")]
+    fn event_stack_token_rewarded(&self, #[indexed] recipient: &ManagedAddress, amount: &BigUint);
+
+    #[event("tokens_staked")]
+    fn event_tokens_staked(&self, #[indexed] staker: &ManagedAddress, amount: &BigUint);
+
+    #[event("tokens_unstaked")]
+    fn event_tokens_unstaked(
+        &self,
+        #[indexed] staker: &ManagedAddress,
+        #[indexed] amount: &BigUint,
+        #[indexed] unbonding_until: u64,
+    );
+
+    #[event("unstaked_withdrawn")]
+    fn event_unstaked_withdrawn(&self, #[indexed] staker: &ManagedAddress, amount: &BigUint);
 
-    #[storage_mapper("answers_by_question")]
-    fn answers_by_question(&self, qid: &u64) -> UnorderedSetMapper<u64>;
+    #[event("platform_fee_updated")]
+    fn event_platform_fee_updated(&self, #[indexed] fee_bps: u64);
 
-    #[storage_mapper("user_questions")]
-    fn user_questions(&self, user: &ManagedAddress) -> UnorderedSetMapper<u64>;
+    #[event("proposal_created")]
+    fn event_proposal_created(
+        &self,
+        #[indexed] proposal_id: &u64,
+        #[indexed] proposer: &ManagedAddress,
+        #[indexed] parameter: &GovernanceParameter,
+        value: &BigUint,
+    );
 
-    #[event("answer_submitted")]
-    fn event_answer_submitted(
+    #[event("proposal_voted")]
+    fn event_proposal_voted(
         &self,
-        #[indexed] answer_id: &u64,
-        #[indexed] question_id: &u64,
-        #[indexed] creator: &ManagedAddress,
+        #[indexed] proposal_id: &u64,
+        #[indexed] voter: &ManagedAddress,
+        #[indexed] support: bool,
+        weight: &BigUint,
     );
 
-    #[event("answer_approved")]
-    fn event_answer_approved(
+    #[event("proposal_executed")]
+    fn event_proposal_executed(&self, #[indexed] proposal_id: &u64);
+
+    #[event("answer_voted")]
+    fn event_answer_voted(
         &self,
-        #[indexed] question_id: &u64,
         #[indexed] answer_id: &u64,
-        #[indexed] rewarded_user: &ManagedAddress,
+        #[indexed] question_id: &u64,
+        #[indexed] voter: &ManagedAddress,
     );
 
-    #[event("tokens_refunded")]
-    fn event_tokens_refunded(
+    #[event("badge_collection_issued")]
+    fn event_badge_collection_issued(&self, #[indexed] token_id: &TokenIdentifier);
+
+    #[event("badge_added")]
+    fn event_badge_added(&self, #[indexed] badge_id: &u64, name: &ManagedBuffer);
+
+    #[event("badge_awarded")]
+    fn event_badge_awarded(
         &self,
-        #[indexed] question_id: &u64,
-        #[indexed] creator: &ManagedAddress,
+        #[indexed] badge_id: &u64,
+        #[indexed] recipient: &ManagedAddress,
+        #[indexed] nonce: u64,
     );
 
+    #[event("badge_claimed")]
+    fn event_badge_claimed(&self, #[indexed] recipient: &ManagedAddress, #[indexed] nonce: u64);
+
+    #[event("treasury_set")]
+    fn event_treasury_set(&self, #[indexed] treasury: &ManagedAddress);
+
     // Owner management endpoints
     #[only_owner]
     #[endpoint(pauseContract)]
@@ -344,12 +3085,244 @@ pub trait StackTokenContract {
         self.event_contract_unpaused();
     }
 
+    // Emergency wind-down: while paused, the owner refunds every open question in resumable
+    // batches regardless of deadline; once all questions are processed it cannot run again
+    #[only_owner]
+    #[endpoint(emergencyRefundBatch)]
+    fn emergency_refund_batch(&self, max_items: u64) -> u64 {
+        require!(self.is_paused().get(), "Contract must be paused");
+        require!(!self.emergency_refund_finished().get(), "Emergency refund already finished");
+        require!(max_items > 0, "Batch size must be positive");
+
+        let total_questions = self.question_id().get();
+        let start = self.emergency_refund_cursor().get() + 1;
+        let end = core::cmp::min(start.saturating_add(max_items - 1), total_questions);
+        let mut refunded = 0u64;
+        for qid in start..=end {
+            let mut question = self.questions(&qid).get();
+            // The wind-down also unwinds jobs still under review, which normal refunds leave alone
+            let under_review = matches!(question.status, QuestionStatus::Delivered | QuestionStatus::Disputed);
+            if !self.is_refundable_status(&question.status) && !under_review {
+                continue;
+            }
+
+            question.status = QuestionStatus::EmergencyRefunded;
+            self.questions(&qid).set(&question);
+            self.release_open_question_slot(&question.creator);
+            let refunded_amount = self.refund_question_funds(&question, true);
+            self.return_answer_stakes(qid, true);
+            self.event_emergency_refund(&qid, &question.creator, &refunded_amount);
+            refunded += 1;
+        }
+        self.emergency_refund_cursor().set(end);
+
+        if end >= total_questions {
+            self.emergency_refund_finished().set(true);
+            self.event_emergency_refund_finished(total_questions);
+        }
+        refunded
+    }
+
+    #[view(getEmergencyRefundProgress)]
+    fn get_emergency_refund_progress(&self) -> MultiValue2<u64, bool> {
+        (
+            self.emergency_refund_cursor().get(),   // last processed question id
+            self.emergency_refund_finished().get(), // wind-down finished
+        ).into()
+    }
+
+    // Timelocked admin actions: sensitive owner changes are queued and can only be executed
+    // once the configured delay has passed, giving users time to react
+    #[only_owner]
+    #[endpoint(queueAdminAction)]
+    fn queue_admin_action(&self, action: AdminAction<Self::Api>) -> u64 {
+        self.validate_admin_action(&action);
+
+        let now = self.blockchain().get_block_timestamp();
+        let action_id = self.admin_action_id().update(|id| {
+            *id += 1;
+            *id
+        });
+        let queued = QueuedAdminAction {
+            action_id,
+            action,
+            queued_at: now,
+            executable_at: now + self.admin_timelock_delay().get(),
+        };
+        self.queued_admin_actions(&action_id).set(&queued);
+        self.pending_admin_action_ids().insert(action_id);
+
+        self.event_admin_action_queued(&action_id, queued.executable_at, &queued.action);
+        action_id
+    }
+
+    #[only_owner]
+    #[endpoint(executeAdminAction)]
+    fn execute_admin_action(&self, action_id: u64) {
+        require!(self.pending_admin_action_ids().contains(&action_id), "Admin action not pending");
+        let queued = self.queued_admin_actions(&action_id).get();
+        require!(
+            self.blockchain().get_block_timestamp() >= queued.executable_at,
+            "Admin action still timelocked"
+        );
+
+        self.pending_admin_action_ids().swap_remove(&action_id);
+        self.queued_admin_actions(&action_id).clear();
+        self.validate_admin_action(&queued.action);
+        self.apply_admin_action(queued.action);
+
+        self.event_admin_action_executed(&action_id);
+    }
+
+    #[only_owner]
+    #[endpoint(cancelAdminAction)]
+    fn cancel_admin_action(&self, action_id: u64) {
+        require!(self.pending_admin_action_ids().swap_remove(&action_id), "Admin action not pending");
+        self.queued_admin_actions(&action_id).clear();
+        self.event_admin_action_cancelled(&action_id);
+    }
+
+    fn validate_admin_action(&self, action: &AdminAction<Self::Api>) {
+        match action {
+            AdminAction::SetPlatformFee(fee_bps) => {
+                require!(*fee_bps <= MAX_PLATFORM_FEE_BPS, "Platform fee too high")
+            },
+            AdminAction::SetFeeDiscount(_, discount_bps) => {
+                require!(*discount_bps <= BPS_DENOMINATOR, "Invalid fee discount")
+            },
+            AdminAction::AddModerator(moderator) => {
+                require!(!self.moderators().contains(moderator), "Already a moderator")
+            },
+            AdminAction::RemoveModerator(moderator) => {
+                require!(self.moderators().contains(moderator), "Not a moderator")
+            },
+            AdminAction::AddToDenylist(address) => {
+                require!(!self.denylist().contains(address), "Address already denylisted")
+            },
+            AdminAction::RemoveFromDenylist(address) => {
+                require!(self.denylist().contains(address), "Address not denylisted")
+            },
+            AdminAction::SetTimelockDelay(delay) => {
+                require!(*delay >= MIN_ADMIN_TIMELOCK_DELAY, "Timelock delay too short");
+                require!(*delay <= MAX_ADMIN_TIMELOCK_DELAY, "Timelock delay too long");
+            },
+            AdminAction::TransferOwnership(_) | AdminAction::SetTreasury(_) => {},
+        }
+    }
+
+    fn apply_admin_action(&self, action: AdminAction<Self::Api>) {
+        let owner = self.blockchain().get_owner_address();
+        match action {
+            AdminAction::TransferOwnership(new_owner) => {
+                let old_owner = self.owner().get();
+                self.owner().set(&new_owner);
+                self.event_ownership_transferred(&old_owner, &new_owner);
+            },
+            AdminAction::SetPlatformFee(fee_bps) => {
+                self.platform_fee_bps().set(fee_bps);
+                self.event_platform_fee_updated(fee_bps);
+            },
+            AdminAction::SetFeeDiscount(min_stake, discount_bps) => {
+                // Answerers staking at least `min_stake` get `discount_bps` off the platform fee
+                self.fee_discount_min_stake().set(&min_stake);
+                self.fee_discount_bps().set(discount_bps);
+            },
+            AdminAction::SetTreasury(treasury) => {
+                self.treasury().set(&treasury);
+                self.event_treasury_set(&treasury);
+            },
+            AdminAction::AddModerator(moderator) => {
+                self.moderators().insert(moderator.clone());
+                self.event_moderator_added(&moderator);
+            },
+            AdminAction::RemoveModerator(moderator) => {
+                self.moderators().swap_remove(&moderator);
+                self.event_moderator_removed(&moderator);
+            },
+            AdminAction::AddToDenylist(address) => {
+                self.denylist().insert(address.clone());
+                self.event_address_denylisted(&address, &owner);
+            },
+            AdminAction::RemoveFromDenylist(address) => {
+                self.denylist().swap_remove(&address);
+                self.event_address_removed_from_denylist(&address, &owner);
+            },
+            AdminAction::SetTimelockDelay(delay) => {
+                self.admin_timelock_delay().set(delay);
+            },
+        }
+    }
+
+    #[view(getPendingAdminActions)]
+    fn get_pending_admin_actions(&self) -> MultiValueEncoded<QueuedAdminAction<Self::Api>> {
+        self.pending_admin_action_ids()
+            .iter()
+            .map(|action_id| self.queued_admin_actions(&action_id).get())
+            .collect()
+    }
+
+    #[view(getAdminTimelockDelay)]
+    fn get_admin_timelock_delay(&self) -> u64 {
+        self.admin_timelock_delay().get()
+    }
+
+    // Moderation: moderators act on the denylist immediately; the owner goes through the timelock
+    #[endpoint(addToDenylist)]
+    fn add_to_denylist(&self, address: ManagedAddress) {
+        self.require_moderator();
+        require!(self.denylist().insert(address.clone()), "Address already denylisted");
+        self.event_address_denylisted(&address, &self.blockchain().get_caller());
+    }
+
+    #[endpoint(removeFromDenylist)]
+    fn remove_from_denylist(&self, address: ManagedAddress) {
+        self.require_moderator();
+        require!(self.denylist().swap_remove(&address), "Address not denylisted");
+        self.event_address_removed_from_denylist(&address, &self.blockchain().get_caller());
+    }
+
+    // A limit of 0 disables the corresponding check
     #[only_owner]
-    #[endpoint(transferOwnership)]
-    fn transfer_ownership(&self, new_owner: ManagedAddress) {
-        let old_owner = self.owner().get();
-        self.owner().set(&new_owner);
-        self.event_ownership_transferred(&old_owner, &new_owner);
+    #[endpoint(setUserLimits)]
+    fn set_user_limits(
+        &self,
+        max_open_questions: u64,
+        max_answers_per_question: u32,
+        post_cooldown: u64,
+    ) {
+        self.max_open_questions_per_user().set(max_open_questions);
+        self.max_answers_per_user_per_question().set(max_answers_per_question);
+        self.post_cooldown().set(post_cooldown);
+        self.event_user_limits_updated(max_open_questions, max_answers_per_question, post_cooldown);
+    }
+
+    #[view(getModerators)]
+    fn get_moderators(&self) -> MultiValueEncoded<ManagedAddress> {
+        self.moderators().iter().collect()
+    }
+
+    #[view(isModerator)]
+    fn is_moderator(&self, address: ManagedAddress) -> bool {
+        self.moderators().contains(&address)
+    }
+
+    #[view(isDenylisted)]
+    fn is_denylisted(&self, address: ManagedAddress) -> bool {
+        self.denylist().contains(&address)
+    }
+
+    #[view(getUserLimits)]
+    fn get_user_limits(&self) -> MultiValue3<u64, u32, u64> {
+        (
+            self.max_open_questions_per_user().get(),
+            self.max_answers_per_user_per_question().get(),
+            self.post_cooldown().get(),
+        ).into()
+    }
+
+    #[view(getOpenQuestionCount)]
+    fn get_open_question_count(&self, address: ManagedAddress) -> u64 {
+        self.open_question_count(&address).get()
     }
 
     // View functions for contract status and statistics
@@ -374,14 +3347,77 @@ pub trait StackTokenContract {
     }
 
     #[view(getContractStats)]
-    fn get_contract_stats(&self) -> MultiValue3<u64, u64, bool> {
+    fn get_contract_stats(&self) -> MultiValue6<u64, u64, bool, BigUint, BigUint, BigUint> {
+        let egld = EgldOrEsdtTokenIdentifier::egld();
+        (
+            self.question_id().get(),         // total questions
+            self.answer_id().get(),           // total answers
+            self.is_paused().get(),           // is paused
+            self.total_locked(&egld).get(),   // locked in open questions
+            self.total_paid_out(&egld).get(), // paid to answerers
+            self.total_refunded(&egld).get(), // refunded to creators
+        ).into()
+    }
+
+    // Solvency accounting: running totals per token of what the contract owes
+    fn track_refund(&self, amount: &BigUint) {
+        let egld = EgldOrEsdtTokenIdentifier::egld();
+        self.total_locked(&egld).update(|total| *total -= amount);
+        self.total_refunded(&egld).update(|total| *total += amount);
+    }
+
+    // Returns sponsor top-ups to their pools and the creator's own deposit to the creator;
+    // milestones that were never approved are marked as refunded
+    fn refund_question_funds(&self, question: &Question<Self::Api>, force_pull: bool) -> BigUint {
+        let mut milestones = self.question_milestones(&question.question_id);
+        for milestone_id in 1..=milestones.len() {
+            let mut milestone = milestones.get(milestone_id);
+            if milestone.status == MilestoneStatus::Pending {
+                milestone.status = MilestoneStatus::Refunded;
+                milestones.set(milestone_id, &milestone);
+            }
+        }
+
+        let returned_to_pools = self.return_sponsor_matches(question.question_id);
+        self.total_locked(&EgldOrEsdtTokenIdentifier::egld())
+            .update(|total| *total -= &returned_to_pools);
+
+        let refund = &question.locked_amount - &returned_to_pools;
+        self.track_refund(&refund);
+        self.payout(&question.creator, &EgldOrEsdtTokenIdentifier::egld(), &refund, force_pull);
+        refund
+    }
+
+    #[view(getTokenTotals)]
+    fn get_token_totals(&self, token: EgldOrEsdtTokenIdentifier) -> MultiValue3<BigUint, BigUint, BigUint> {
         (
-            self.question_id().get(),  // total questions
-            self.answer_id().get(),    // total answers
-            self.is_paused().get(),    // is paused
+            self.total_locked(&token).get(),
+            self.total_paid_out(&token).get(),
+            self.total_refunded(&token).get(),
         ).into()
     }
 
+    // Compares the actual balance with everything still owed in this token: open bounties,
+    // unclaimed payouts, (for EGLD) answer stakes and sponsor pools held in escrow and
+    // (for the StackToken) staked and unbonding tokens
+    #[view(checkSolvency)]
+    fn check_solvency(&self, token: EgldOrEsdtTokenIdentifier) -> MultiValue3<BigUint, BigUint, bool> {
+        let balance = self.blockchain().get_sc_balance(&token, 0);
+        let mut owed = self.total_locked(&token).get() + self.total_unclaimed(&token).get();
+        if token.is_egld() {
+            owed += self.total_answer_stakes().get();
+            owed += self.total_sponsor_pool_balance().get();
+        }
+        if self.stack_token().get_token_state().is_set()
+            && token == EgldOrEsdtTokenIdentifier::esdt(self.stack_token().get_token_id())
+        {
+            owed += self.total_staked().get();
+            owed += self.total_unbonding().get();
+        }
+        let solvent = balance >= owed;
+        (balance, owed, solvent).into()
+    }
+
     // Storage mappers for owner and pause functionality
     #[storage_mapper("owner")]
     fn owner(&self) -> SingleValueMapper<ManagedAddress>;
@@ -402,4 +3438,379 @@ pub trait StackTokenContract {
         #[indexed] old_owner: &ManagedAddress,
         #[indexed] new_owner: &ManagedAddress,
     );
+
+    // Storage mappers for profiles
+    #[storage_mapper("profiles")]
+    fn profiles(&self, address: &ManagedAddress) -> SingleValueMapper<Profile<Self::Api>>;
+
+    #[storage_mapper("display_name_owner")]
+    fn display_name_owner(&self, name_key: &ManagedBuffer) -> SingleValueMapper<ManagedAddress>;
+
+    #[storage_mapper("users_by_skill")]
+    fn users_by_skill(&self, skill: &ManagedBuffer) -> UnorderedSetMapper<ManagedAddress>;
+
+    // Storage mappers for comments
+    #[storage_mapper("comment_id")]
+    fn comment_id(&self) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("comments")]
+    fn comments(&self, comment_id: &u64) -> SingleValueMapper<Comment<Self::Api>>;
+
+    #[storage_mapper("comments_by_target")]
+    fn comments_by_target(&self, target: &CommentTarget) -> VecMapper<u64>;
+
+    // Storage mappers for answer tips
+    #[storage_mapper("tip_total")]
+    fn tip_total(&self, answer_id: &u64, token: &EgldOrEsdtTokenIdentifier) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("tip_tokens")]
+    fn tip_tokens(&self, answer_id: &u64) -> UnorderedSetMapper<EgldOrEsdtTokenIdentifier>;
+
+    // Storage mappers for puzzle bounties
+    #[storage_mapper("puzzle_hash")]
+    fn puzzle_hash(&self, question_id: &u64) -> SingleValueMapper<ManagedByteArray<32>>;
+
+    #[storage_mapper("puzzle_commitment")]
+    fn puzzle_commitment(&self, question_id: &u64, solver: &ManagedAddress) -> SingleValueMapper<ManagedByteArray<32>>;
+
+    #[storage_mapper("puzzle_commit_nonce")]
+    fn puzzle_commit_nonce(&self, question_id: &u64, solver: &ManagedAddress) -> SingleValueMapper<u64>;
+
+    // Storage mappers for invite-only questions
+    #[storage_mapper("invite_only")]
+    fn invite_only(&self, question_id: &u64) -> SingleValueMapper<bool>;
+
+    #[storage_mapper("allowed_answerers")]
+    fn allowed_answerers(&self, question_id: &u64) -> UnorderedSetMapper<ManagedAddress>;
+
+    // Storage mappers for delegated reviewers
+    #[storage_mapper("question_reviewers")]
+    fn question_reviewers(&self, question_id: &u64) -> UnorderedSetMapper<ManagedAddress>;
+
+    #[storage_mapper("required_approvals")]
+    fn required_approvals(&self, question_id: &u64) -> SingleValueMapper<u32>;
+
+    #[storage_mapper("reviewer_approvals")]
+    fn reviewer_approvals(&self, answer_id: &u64) -> UnorderedSetMapper<ManagedAddress>;
+
+    // Storage mappers for assigned jobs
+    #[storage_mapper("job_assignment")]
+    fn job_assignment(&self, question_id: &u64) -> SingleValueMapper<JobAssignment<Self::Api>>;
+
+    // Storage mappers for vested payouts
+    #[storage_mapper("vesting_period")]
+    fn vesting_period(&self, question_id: &u64) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("vestings")]
+    fn vestings(&self, question_id: &u64) -> SingleValueMapper<Vesting<Self::Api>>;
+
+    // Storage mappers for decaying bounties
+    #[storage_mapper("bounty_decay")]
+    fn bounty_decay(&self, question_id: &u64) -> SingleValueMapper<DecayCurve>;
+
+    // Storage mappers for competition questions
+    #[storage_mapper("competition_schedule")]
+    fn competition_schedule(&self, question_id: &u64) -> VecMapper<u64>;
+
+    // Storage mappers for milestone bounties
+    #[storage_mapper("question_milestones")]
+    fn question_milestones(&self, question_id: &u64) -> VecMapper<Milestone<Self::Api>>;
+
+    // Storage mappers for tags and sponsored tag pools
+    #[storage_mapper("question_tags")]
+    fn question_tags(&self, question_id: &u64) -> UnorderedSetMapper<ManagedBuffer>;
+
+    #[storage_mapper("sponsor_pool_id")]
+    fn sponsor_pool_id(&self) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("sponsor_pools")]
+    fn sponsor_pools(&self, pool_id: &u64) -> SingleValueMapper<SponsorPool<Self::Api>>;
+
+    #[storage_mapper("pools_by_tag")]
+    fn pools_by_tag(&self, tag: &ManagedBuffer) -> UnorderedSetMapper<u64>;
+
+    #[storage_mapper("pool_matches")]
+    fn pool_matches(&self, pool_id: &u64) -> VecMapper<SponsorMatch<Self::Api>>;
+
+    #[storage_mapper("question_sponsor_matches")]
+    fn question_sponsor_matches(&self, question_id: &u64) -> VecMapper<SponsorMatch<Self::Api>>;
+
+    #[storage_mapper("total_sponsor_pool_balance")]
+    fn total_sponsor_pool_balance(&self) -> SingleValueMapper<BigUint>;
+
+    // Storage mappers for solvency accounting
+    #[storage_mapper("total_locked")]
+    fn total_locked(&self, token: &EgldOrEsdtTokenIdentifier) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("total_paid_out")]
+    fn total_paid_out(&self, token: &EgldOrEsdtTokenIdentifier) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("total_refunded")]
+    fn total_refunded(&self, token: &EgldOrEsdtTokenIdentifier) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("total_unclaimed")]
+    fn total_unclaimed(&self, token: &EgldOrEsdtTokenIdentifier) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("total_answer_stakes")]
+    fn total_answer_stakes(&self) -> SingleValueMapper<BigUint>;
+
+    // Storage mappers for the emergency wind-down
+    #[storage_mapper("emergency_refund_cursor")]
+    fn emergency_refund_cursor(&self) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("emergency_refund_finished")]
+    fn emergency_refund_finished(&self) -> SingleValueMapper<bool>;
+
+    // Storage mappers for timelocked admin actions
+    #[storage_mapper("admin_action_id")]
+    fn admin_action_id(&self) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("queued_admin_actions")]
+    fn queued_admin_actions(&self, action_id: &u64) -> SingleValueMapper<QueuedAdminAction<Self::Api>>;
+
+    #[storage_mapper("pending_admin_action_ids")]
+    fn pending_admin_action_ids(&self) -> UnorderedSetMapper<u64>;
+
+    #[storage_mapper("admin_timelock_delay")]
+    fn admin_timelock_delay(&self) -> SingleValueMapper<u64>;
+
+    // Storage mappers for moderation and per-user limits
+    #[storage_mapper("moderators")]
+    fn moderators(&self) -> UnorderedSetMapper<ManagedAddress>;
+
+    #[storage_mapper("denylist")]
+    fn denylist(&self) -> UnorderedSetMapper<ManagedAddress>;
+
+    #[storage_mapper("max_open_questions_per_user")]
+    fn max_open_questions_per_user(&self) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("max_answers_per_user_per_question")]
+    fn max_answers_per_user_per_question(&self) -> SingleValueMapper<u32>;
+
+    #[storage_mapper("post_cooldown")]
+    fn post_cooldown(&self) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("open_question_count")]
+    fn open_question_count(&self, user: &ManagedAddress) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("user_answer_count")]
+    fn user_answer_count(&self, question_id: &u64, user: &ManagedAddress) -> SingleValueMapper<u32>;
+
+    #[storage_mapper("last_post_timestamp")]
+    fn last_post_timestamp(&self, user: &ManagedAddress) -> SingleValueMapper<u64>;
+
+    // Events for puzzle bounties
+    #[event("puzzle_solution_committed")]
+    fn event_puzzle_solution_committed(&self, #[indexed] question_id: &u64, #[indexed] solver: &ManagedAddress);
+
+    #[event("puzzle_solved")]
+    fn event_puzzle_solved(&self, #[indexed] question_id: &u64, #[indexed] solver: &ManagedAddress);
+
+    // Events for invite-only questions
+    #[event("allowlist_updated")]
+    fn event_allowlist_updated(&self, #[indexed] question_id: &u64, #[indexed] allowed_count: u32);
+
+    // Events for delegated reviewers
+    #[event("reviewers_set")]
+    fn event_reviewers_set(
+        &self,
+        #[indexed] question_id: &u64,
+        #[indexed] required_approvals: u32,
+        #[indexed] reviewer_count: u32,
+    );
+
+    #[event("reviewer_approved")]
+    fn event_reviewer_approved(
+        &self,
+        #[indexed] question_id: &u64,
+        #[indexed] answer_id: &u64,
+        #[indexed] reviewer: &ManagedAddress,
+    );
+
+    // Events for assigned jobs
+    #[event("job_assigned")]
+    fn event_job_assigned(
+        &self,
+        #[indexed] question_id: &u64,
+        #[indexed] assignee: &ManagedAddress,
+        #[indexed] review_window: u64,
+    );
+
+    #[event("job_accepted")]
+    fn event_job_accepted(&self, #[indexed] question_id: &u64, #[indexed] assignee: &ManagedAddress);
+
+    #[event("job_delivered")]
+    fn event_job_delivered(&self, #[indexed] question_id: &u64, #[indexed] answer_id: &u64);
+
+    #[event("job_disputed")]
+    fn event_job_disputed(&self, #[indexed] question_id: &u64);
+
+    #[event("job_auto_released")]
+    fn event_job_auto_released(&self, #[indexed] question_id: &u64, #[indexed] assignee: &ManagedAddress);
+
+    #[event("dispute_resolved")]
+    fn event_dispute_resolved(&self, #[indexed] question_id: &u64, #[indexed] released_to_assignee: bool);
+
+    // Events for vested payouts
+    #[event("vesting_period_set")]
+    fn event_vesting_period_set(&self, #[indexed] question_id: &u64, #[indexed] period: u64);
+
+    #[event("vesting_started")]
+    fn event_vesting_started(
+        &self,
+        #[indexed] question_id: &u64,
+        #[indexed] beneficiary: &ManagedAddress,
+        #[indexed] end: u64,
+        amount: &BigUint,
+    );
+
+    #[event("vested_claimed")]
+    fn event_vested_claimed(
+        &self,
+        #[indexed] question_id: &u64,
+        #[indexed] beneficiary: &ManagedAddress,
+        amount: &BigUint,
+    );
+
+    #[event("vesting_disputed")]
+    fn event_vesting_disputed(&self, #[indexed] question_id: &u64, #[indexed] frozen_at: u64);
+
+    #[event("vesting_dispute_resolved")]
+    fn event_vesting_dispute_resolved(&self, #[indexed] question_id: &u64, #[indexed] released_to_answerer: bool);
+
+    // Events for competition questions
+    #[event("competition_winner")]
+    fn event_competition_winner(
+        &self,
+        #[indexed] question_id: &u64,
+        #[indexed] answer_id: &u64,
+        #[indexed] rank: usize,
+        amount: &BigUint,
+    );
+
+    // Events for milestone bounties
+    #[event("milestone_approved")]
+    fn event_milestone_approved(
+        &self,
+        #[indexed] question_id: &u64,
+        #[indexed] milestone_id: usize,
+        #[indexed] answer_id: &u64,
+        amount: &BigUint,
+    );
+
+    // Events for sponsored tag pools
+    #[event("sponsor_pool_created")]
+    fn event_sponsor_pool_created(
+        &self,
+        #[indexed] pool_id: &u64,
+        #[indexed] sponsor: &ManagedAddress,
+        #[indexed] tag: &ManagedBuffer,
+        deposit: &BigUint,
+    );
+
+    #[event("sponsor_pool_funded")]
+    fn event_sponsor_pool_funded(&self, #[indexed] pool_id: &u64, amount: &BigUint);
+
+    #[event("sponsor_pool_withdrawn")]
+    fn event_sponsor_pool_withdrawn(&self, #[indexed] pool_id: &u64, amount: &BigUint);
+
+    #[event("sponsor_match")]
+    fn event_sponsor_match(
+        &self,
+        #[indexed] pool_id: &u64,
+        #[indexed] question_id: &u64,
+        amount: &BigUint,
+    );
+
+    // Events for duplicate closure
+    #[event("question_closed_as_duplicate")]
+    fn event_question_closed_as_duplicate(
+        &self,
+        #[indexed] question_id: &u64,
+        #[indexed] canonical_id: &u64,
+        moved_amount: &BigUint,
+    );
+
+    // Events for profiles
+    #[event("profile_updated")]
+    fn event_profile_updated(&self, #[indexed] address: &ManagedAddress, display_name: &ManagedBuffer);
+
+    // Events for comments
+    #[event("comment_added")]
+    fn event_comment_added(
+        &self,
+        #[indexed] comment_id: &u64,
+        #[indexed] author: &ManagedAddress,
+        #[indexed] target: &CommentTarget,
+    );
+
+    #[event("comment_hidden")]
+    fn event_comment_hidden(&self, #[indexed] comment_id: &u64, #[indexed] hidden: bool);
+
+    // Events for answer tips
+    #[event("tip_sent")]
+    fn event_tip_sent(
+        &self,
+        #[indexed] answer_id: &u64,
+        #[indexed] from: &ManagedAddress,
+        #[indexed] to: &ManagedAddress,
+        #[indexed] token: &EgldOrEsdtTokenIdentifier,
+        amount: &BigUint,
+    );
+
+    // Events for the emergency wind-down
+    #[event("emergency_refund")]
+    fn event_emergency_refund(
+        &self,
+        #[indexed] question_id: &u64,
+        #[indexed] creator: &ManagedAddress,
+        amount: &BigUint,
+    );
+
+    #[event("emergency_refund_finished")]
+    fn event_emergency_refund_finished(&self, #[indexed] total_questions: u64);
+
+    // Events for timelocked admin actions
+    #[event("admin_action_queued")]
+    fn event_admin_action_queued(
+        &self,
+        #[indexed] action_id: &u64,
+        #[indexed] executable_at: u64,
+        action: &AdminAction<Self::Api>,
+    );
+
+    #[event("admin_action_executed")]
+    fn event_admin_action_executed(&self, #[indexed] action_id: &u64);
+
+    #[event("admin_action_cancelled")]
+    fn event_admin_action_cancelled(&self, #[indexed] action_id: &u64);
+
+    // Events for moderation and per-user limits
+    #[event("moderator_added")]
+    fn event_moderator_added(&self, #[indexed] moderator: &ManagedAddress);
+
+    #[event("moderator_removed")]
+    fn event_moderator_removed(&self, #[indexed] moderator: &ManagedAddress);
+
+    #[event("address_denylisted")]
+    fn event_address_denylisted(
+        &self,
+        #[indexed] address: &ManagedAddress,
+        #[indexed] by: &ManagedAddress,
+    );
+
+    #[event("address_removed_from_denylist")]
+    fn event_address_removed_from_denylist(
+        &self,
+        #[indexed] address: &ManagedAddress,
+        #[indexed] by: &ManagedAddress,
+    );
+
+    #[event("user_limits_updated")]
+    fn event_user_limits_updated(
+        &self,
+        #[indexed] max_open_questions: u64,
+        #[indexed] max_answers_per_question: u32,
+        #[indexed] post_cooldown: u64,
+    );
 }
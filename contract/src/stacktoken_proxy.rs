@@ -66,124 +66,2115 @@ where
         Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
         Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
         Arg2: ProxyArg<u64>,
+        Arg3: ProxyArg<MultiValueEncoded<Env::Api, ManagedBuffer<Env::Api>>>,
     >(
         self,
         title: Arg0,
         description: Arg1,
         deadline: Arg2,
+        tags: Arg3,
     ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
         self.wrapped_tx
             .raw_call("postQuestion")
             .argument(&title)
             .argument(&description)
             .argument(&deadline)
+            .argument(&tags)
+            .original_result()
+    }
+
+    pub fn post_milestone_question<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg2: ProxyArg<u64>,
+        Arg3: ProxyArg<MultiValueEncoded<Env::Api, MultiValue2<ManagedBuffer<Env::Api>, BigUint<Env::Api>>>>,
+    >(
+        self,
+        title: Arg0,
+        description: Arg1,
+        deadline: Arg2,
+        milestones: Arg3,
+    ) -> TxTypedCall<Env, From, To, (), Gas, u64> {
+        self.wrapped_tx
+            .raw_call("postMilestoneQuestion")
+            .argument(&title)
+            .argument(&description)
+            .argument(&deadline)
+            .argument(&milestones)
+            .original_result()
+    }
+
+    pub fn post_invite_only_question<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg2: ProxyArg<u64>,
+        Arg3: ProxyArg<MultiValueEncoded<Env::Api, ManagedAddress<Env::Api>>>,
+    >(
+        self,
+        title: Arg0,
+        description: Arg1,
+        deadline: Arg2,
+        answerers: Arg3,
+    ) -> TxTypedCall<Env, From, To, (), Gas, u64> {
+        self.wrapped_tx
+            .raw_call("postInviteOnlyQuestion")
+            .argument(&title)
+            .argument(&description)
+            .argument(&deadline)
+            .argument(&answerers)
+            .original_result()
+    }
+
+    pub fn post_puzzle_question<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg2: ProxyArg<u64>,
+        Arg3: ProxyArg<ManagedByteArray<Env::Api, 32usize>>,
+    >(
+        self,
+        title: Arg0,
+        description: Arg1,
+        deadline: Arg2,
+        answer_hash: Arg3,
+    ) -> TxTypedCall<Env, From, To, (), Gas, u64> {
+        self.wrapped_tx
+            .raw_call("postPuzzleQuestion")
+            .argument(&title)
+            .argument(&description)
+            .argument(&deadline)
+            .argument(&answer_hash)
+            .original_result()
+    }
+
+    pub fn post_competition_question<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg2: ProxyArg<u64>,
+        Arg3: ProxyArg<MultiValueEncoded<Env::Api, u64>>,
+    >(
+        self,
+        title: Arg0,
+        description: Arg1,
+        deadline: Arg2,
+        payout_schedule_bps: Arg3,
+    ) -> TxTypedCall<Env, From, To, (), Gas, u64> {
+        self.wrapped_tx
+            .raw_call("postCompetitionQuestion")
+            .argument(&title)
+            .argument(&description)
+            .argument(&deadline)
+            .argument(&payout_schedule_bps)
+            .original_result()
+    }
+
+    pub fn post_decaying_question<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg2: ProxyArg<u64>,
+        Arg3: ProxyArg<DecayCurve>,
+    >(
+        self,
+        title: Arg0,
+        description: Arg1,
+        deadline: Arg2,
+        decay: Arg3,
+    ) -> TxTypedCall<Env, From, To, (), Gas, u64> {
+        self.wrapped_tx
+            .raw_call("postDecayingQuestion")
+            .argument(&title)
+            .argument(&description)
+            .argument(&deadline)
+            .argument(&decay)
             .original_result()
     }
 
     pub fn submit_answer<
         Arg0: ProxyArg<u64>,
-        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
-        Arg2: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg2: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        question_id: Arg0,
+        title: Arg1,
+        description: Arg2,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+        self.wrapped_tx
+            .raw_call("submitAnswer")
+            .argument(&question_id)
+            .argument(&title)
+            .argument(&description)
+            .original_result()
+    }
+
+    pub fn commit_answer<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<ManagedByteArray<Env::Api, 32usize>>,
+    >(
+        self,
+        question_id: Arg0,
+        commitment: Arg1,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+        self.wrapped_tx
+            .raw_call("commitAnswer")
+            .argument(&question_id)
+            .argument(&commitment)
+            .original_result()
+    }
+
+    pub fn reveal_answer<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg2: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg3: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        answer_id: Arg0,
+        title: Arg1,
+        description: Arg2,
+        salt: Arg3,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("revealAnswer")
+            .argument(&answer_id)
+            .argument(&title)
+            .argument(&description)
+            .argument(&salt)
+            .original_result()
+    }
+
+    pub fn enable_commit_reveal<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<u64>,
+    >(
+        self,
+        question_id: Arg0,
+        reveal_period: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("enableCommitReveal")
+            .argument(&question_id)
+            .argument(&reveal_period)
+            .original_result()
+    }
+
+    pub fn vote_answer<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        answer_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("voteAnswer")
+            .argument(&answer_id)
+            .original_result()
+    }
+
+    pub fn add_comment<
+        Arg0: ProxyArg<CommentTarget>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg2: ProxyArg<OptionalValue<u64>>,
+    >(
+        self,
+        target: Arg0,
+        content: Arg1,
+        reply_to: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("addComment")
+            .argument(&target)
+            .argument(&content)
+            .argument(&reply_to)
+            .original_result()
+    }
+
+    pub fn set_comment_hidden<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<bool>,
+    >(
+        self,
+        comment_id: Arg0,
+        hidden: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setCommentHidden")
+            .argument(&comment_id)
+            .argument(&hidden)
+            .original_result()
+    }
+
+    pub fn get_comments<
+        Arg0: ProxyArg<CommentTarget>,
+        Arg1: ProxyArg<usize>,
+        Arg2: ProxyArg<usize>,
+    >(
+        self,
+        target: Arg0,
+        offset: Arg1,
+        limit: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, Comment<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getComments")
+            .argument(&target)
+            .argument(&offset)
+            .argument(&limit)
+            .original_result()
+    }
+
+    pub fn get_comment_count<
+        Arg0: ProxyArg<CommentTarget>,
+    >(
+        self,
+        target: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, usize> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getCommentCount")
+            .argument(&target)
+            .original_result()
+    }
+
+    pub fn set_profile<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg2: ProxyArg<MultiValueEncoded<Env::Api, ManagedBuffer<Env::Api>>>,
+    >(
+        self,
+        display_name: Arg0,
+        bio_uri: Arg1,
+        skills: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setProfile")
+            .argument(&display_name)
+            .argument(&bio_uri)
+            .argument(&skills)
+            .original_result()
+    }
+
+    pub fn get_profile<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        address: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, OptionalValue<Profile<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getProfile")
+            .argument(&address)
+            .original_result()
+    }
+
+    pub fn get_users_by_skill<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        skill: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, ManagedAddress<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getUsersBySkill")
+            .argument(&skill)
+            .original_result()
+    }
+
+    pub fn get_address_by_display_name<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        display_name: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, OptionalValue<ManagedAddress<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getAddressByDisplayName")
+            .argument(&display_name)
+            .original_result()
+    }
+
+    pub fn tip_answer<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        answer_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+        self.wrapped_tx
+            .raw_call("tipAnswer")
+            .argument(&answer_id)
+            .original_result()
+    }
+
+    pub fn create_sponsor_pool<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<BigUint<Env::Api>>,
+        Arg2: ProxyArg<u64>,
+    >(
+        self,
+        tag: Arg0,
+        match_amount: Arg1,
+        match_ratio_bps: Arg2,
+    ) -> TxTypedCall<Env, From, To, (), Gas, u64> {
+        self.wrapped_tx
+            .raw_call("createSponsorPool")
+            .argument(&tag)
+            .argument(&match_amount)
+            .argument(&match_ratio_bps)
+            .original_result()
+    }
+
+    pub fn fund_sponsor_pool<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        pool_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+        self.wrapped_tx
+            .raw_call("fundSponsorPool")
+            .argument(&pool_id)
+            .original_result()
+    }
+
+    pub fn withdraw_sponsor_pool<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        pool_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("withdrawSponsorPool")
+            .argument(&pool_id)
+            .original_result()
+    }
+
+    pub fn get_sponsor_pool<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        pool_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, OptionalValue<SponsorPool<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getSponsorPool")
+            .argument(&pool_id)
+            .original_result()
+    }
+
+    pub fn get_pools_by_tag<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        tag: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, SponsorPool<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getPoolsByTag")
+            .argument(&tag)
+            .original_result()
+    }
+
+    pub fn get_pool_matches<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        pool_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, SponsorMatch<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getPoolMatches")
+            .argument(&pool_id)
+            .original_result()
+    }
+
+    pub fn get_question_tags<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        question_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, ManagedBuffer<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getQuestionTags")
+            .argument(&question_id)
+            .original_result()
+    }
+
+    pub fn get_answer_tips<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        answer_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, MultiValue2<EgldOrEsdtTokenIdentifier<Env::Api>, BigUint<Env::Api>>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getAnswerTips")
+            .argument(&answer_id)
+            .original_result()
+    }
+
+    pub fn approve_answer<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<u64>,
+    >(
+        self,
+        question_id: Arg0,
+        answer_id: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("approveAnswer")
+            .argument(&question_id)
+            .argument(&answer_id)
+            .original_result()
+    }
+
+    pub fn set_reviewers<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<u32>,
+        Arg2: ProxyArg<MultiValueEncoded<Env::Api, ManagedAddress<Env::Api>>>,
+    >(
+        self,
+        question_id: Arg0,
+        required_approvals: Arg1,
+        reviewers: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setReviewers")
+            .argument(&question_id)
+            .argument(&required_approvals)
+            .argument(&reviewers)
+            .original_result()
+    }
+
+    pub fn get_current_payout<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        question_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getCurrentPayout")
+            .argument(&question_id)
+            .original_result()
+    }
+
+    pub fn get_bounty_decay<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        question_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, OptionalValue<DecayCurve>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getBountyDecay")
+            .argument(&question_id)
+            .original_result()
+    }
+
+    pub fn rank_answers<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<MultiValueEncoded<Env::Api, u64>>,
+    >(
+        self,
+        question_id: Arg0,
+        ordered_ids: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("rankAnswers")
+            .argument(&question_id)
+            .argument(&ordered_ids)
+            .original_result()
+    }
+
+    pub fn get_payout_schedule<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        question_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, u64>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getPayoutSchedule")
+            .argument(&question_id)
+            .original_result()
+    }
+
+    pub fn set_vesting_period<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<u64>,
+    >(
+        self,
+        question_id: Arg0,
+        period: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setVestingPeriod")
+            .argument(&question_id)
+            .argument(&period)
+            .original_result()
+    }
+
+    pub fn claim_vested<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        question_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("claimVested")
+            .argument(&question_id)
+            .original_result()
+    }
+
+    pub fn dispute_vesting<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        question_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("disputeVesting")
+            .argument(&question_id)
+            .original_result()
+    }
+
+    pub fn resolve_vesting_dispute<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<bool>,
+    >(
+        self,
+        question_id: Arg0,
+        release_to_answerer: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("resolveVestingDispute")
+            .argument(&question_id)
+            .argument(&release_to_answerer)
+            .original_result()
+    }
+
+    pub fn get_vesting<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        question_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, OptionalValue<Vesting<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getVesting")
+            .argument(&question_id)
+            .original_result()
+    }
+
+    pub fn get_vested_claimable<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        question_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getVestedClaimable")
+            .argument(&question_id)
+            .original_result()
+    }
+
+    pub fn get_vesting_period<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        question_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getVestingPeriod")
+            .argument(&question_id)
+            .original_result()
+    }
+
+    pub fn approve_milestone<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<usize>,
+        Arg2: ProxyArg<u64>,
+    >(
+        self,
+        question_id: Arg0,
+        milestone_id: Arg1,
+        answer_id: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("approveMilestone")
+            .argument(&question_id)
+            .argument(&milestone_id)
+            .argument(&answer_id)
+            .original_result()
+    }
+
+    pub fn get_milestones<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        question_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, Milestone<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getMilestones")
+            .argument(&question_id)
+            .original_result()
+    }
+
+    pub fn refund_question<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        question_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("refundQuestion")
+            .argument(&question_id)
+            .original_result()
+    }
+
+    pub fn expire_questions(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("expireQuestions")
+            .original_result()
+    }
+
+    pub fn add_allowed_answerers<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<MultiValueEncoded<Env::Api, ManagedAddress<Env::Api>>>,
+    >(
+        self,
+        question_id: Arg0,
+        answerers: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("addAllowedAnswerers")
+            .argument(&question_id)
+            .argument(&answerers)
+            .original_result()
+    }
+
+    pub fn remove_allowed_answerers<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<MultiValueEncoded<Env::Api, ManagedAddress<Env::Api>>>,
+    >(
+        self,
+        question_id: Arg0,
+        answerers: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("removeAllowedAnswerers")
+            .argument(&question_id)
+            .argument(&answerers)
+            .original_result()
+    }
+
+    pub fn can_answer<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        question_id: Arg0,
+        address: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("canAnswer")
+            .argument(&question_id)
+            .argument(&address)
+            .original_result()
+    }
+
+    pub fn get_allowed_answerers<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        question_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, ManagedAddress<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getAllowedAnswerers")
+            .argument(&question_id)
+            .original_result()
+    }
+
+    pub fn commit_puzzle_solution<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<ManagedByteArray<Env::Api, 32usize>>,
+    >(
+        self,
+        question_id: Arg0,
+        commitment: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("commitPuzzleSolution")
+            .argument(&question_id)
+            .argument(&commitment)
+            .original_result()
+    }
+
+    pub fn solve_puzzle<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        question_id: Arg0,
+        preimage: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("solvePuzzle")
+            .argument(&question_id)
+            .argument(&preimage)
+            .original_result()
+    }
+
+    pub fn compute_puzzle_commitment<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        solver: Arg0,
+        preimage: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedByteArray<Env::Api, 32usize>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("computePuzzleCommitment")
+            .argument(&solver)
+            .argument(&preimage)
+            .original_result()
+    }
+
+    pub fn get_puzzle_hash<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        question_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, OptionalValue<ManagedByteArray<Env::Api, 32usize>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getPuzzleHash")
+            .argument(&question_id)
+            .original_result()
+    }
+
+    pub fn close_as_duplicate<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<u64>,
+        Arg2: ProxyArg<bool>,
+    >(
+        self,
+        question_id: Arg0,
+        canonical_id: Arg1,
+        move_bounty: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("closeAsDuplicate")
+            .argument(&question_id)
+            .argument(&canonical_id)
+            .argument(&move_bounty)
+            .original_result()
+    }
+
+    pub fn assign_question<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg2: ProxyArg<u64>,
+    >(
+        self,
+        question_id: Arg0,
+        assignee: Arg1,
+        review_window: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("assignQuestion")
+            .argument(&question_id)
+            .argument(&assignee)
+            .argument(&review_window)
+            .original_result()
+    }
+
+    pub fn accept_assignment<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        question_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("acceptAssignment")
+            .argument(&question_id)
+            .original_result()
+    }
+
+    pub fn mark_delivered<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg2: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        question_id: Arg0,
+        title: Arg1,
+        description: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("markDelivered")
+            .argument(&question_id)
+            .argument(&title)
+            .argument(&description)
+            .original_result()
+    }
+
+    pub fn dispute_delivery<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        question_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("disputeDelivery")
+            .argument(&question_id)
+            .original_result()
+    }
+
+    pub fn release_delivery<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        question_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("releaseDelivery")
+            .argument(&question_id)
+            .original_result()
+    }
+
+    pub fn resolve_dispute<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<bool>,
+    >(
+        self,
+        question_id: Arg0,
+        release_to_assignee: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("resolveDispute")
+            .argument(&question_id)
+            .argument(&release_to_assignee)
+            .original_result()
+    }
+
+    pub fn get_reviewers<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        question_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValue2<u32, MultiValueEncoded<Env::Api, ManagedAddress<Env::Api>>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getReviewers")
+            .argument(&question_id)
+            .original_result()
+    }
+
+    pub fn get_reviewer_approvals<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        answer_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, ManagedAddress<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getReviewerApprovals")
+            .argument(&answer_id)
+            .original_result()
+    }
+
+    pub fn get_job_assignment<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        question_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, OptionalValue<JobAssignment<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getJobAssignment")
+            .argument(&question_id)
+            .original_result()
+    }
+
+    pub fn set_answer_stake<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<BigUint<Env::Api>>,
+    >(
+        self,
+        question_id: Arg0,
+        amount: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setAnswerStake")
+            .argument(&question_id)
+            .argument(&amount)
+            .original_result()
+    }
+
+    pub fn mark_answer_as_spam<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        answer_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("markAnswerAsSpam")
+            .argument(&answer_id)
+            .original_result()
+    }
+
+    pub fn claim(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("claim")
+            .original_result()
+    }
+
+    pub fn set_pull_payouts<
+        Arg0: ProxyArg<bool>,
+    >(
+        self,
+        enabled: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setPullPayouts")
+            .argument(&enabled)
+            .original_result()
+    }
+
+    pub fn issue_stack_token<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg2: ProxyArg<usize>,
+    >(
+        self,
+        token_display_name: Arg0,
+        token_ticker: Arg1,
+        num_decimals: Arg2,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+        self.wrapped_tx
+            .raw_call("issueStackToken")
+            .argument(&token_display_name)
+            .argument(&token_ticker)
+            .argument(&num_decimals)
+            .original_result()
+    }
+
+    pub fn set_stack_token_rewards<
+        Arg0: ProxyArg<BigUint<Env::Api>>,
+        Arg1: ProxyArg<BigUint<Env::Api>>,
+    >(
+        self,
+        answerer_reward: Arg0,
+        asker_reward: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setStackTokenRewards")
+            .argument(&answerer_reward)
+            .argument(&asker_reward)
+            .original_result()
+    }
+
+    pub fn get_stack_token_id(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, OptionalValue<TokenIdentifier<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getStackTokenId")
+            .original_result()
+    }
+
+    pub fn get_stack_token_rewards(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValue2<BigUint<Env::Api>, BigUint<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getStackTokenRewards")
+            .original_result()
+    }
+
+    pub fn stake(
+        self,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+        self.wrapped_tx
+            .raw_call("stake")
+            .original_result()
+    }
+
+    pub fn unstake<
+        Arg0: ProxyArg<BigUint<Env::Api>>,
+    >(
+        self,
+        amount: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("unstake")
+            .argument(&amount)
+            .original_result()
+    }
+
+    pub fn withdraw_unstaked(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("withdrawUnstaked")
+            .original_result()
+    }
+
+    pub fn set_unbonding_period<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        unbonding_period: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setUnbondingPeriod")
+            .argument(&unbonding_period)
+            .original_result()
+    }
+
+    pub fn set_vote_weight_params<
+        Arg0: ProxyArg<BigUint<Env::Api>>,
+        Arg1: ProxyArg<u32>,
+    >(
+        self,
+        stake_per_extra_vote: Arg0,
+        max_vote_weight: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setVoteWeightParams")
+            .argument(&stake_per_extra_vote)
+            .argument(&max_vote_weight)
+            .original_result()
+    }
+
+    pub fn get_vote_weight<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        address: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u32> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getVoteWeight")
+            .argument(&address)
+            .original_result()
+    }
+
+    pub fn get_platform_fee_for<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<BigUint<Env::Api>>,
+    >(
+        self,
+        recipient: Arg0,
+        amount: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getPlatformFeeFor")
+            .argument(&recipient)
+            .argument(&amount)
+            .original_result()
+    }
+
+    pub fn get_staked_amount<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        address: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getStakedAmount")
+            .argument(&address)
+            .original_result()
+    }
+
+    pub fn get_stake_locked_until<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        address: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getStakeLockedUntil")
+            .argument(&address)
+            .original_result()
+    }
+
+    pub fn get_unbonding<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        address: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValue2<BigUint<Env::Api>, u64>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getUnbonding")
+            .argument(&address)
+            .original_result()
+    }
+
+    pub fn get_total_staked(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getTotalStaked")
+            .original_result()
+    }
+
+    pub fn get_platform_fee(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getPlatformFee")
+            .original_result()
+    }
+
+    pub fn propose_parameter_change<
+        Arg0: ProxyArg<GovernanceParameter>,
+        Arg1: ProxyArg<BigUint<Env::Api>>,
+    >(
+        self,
+        parameter: Arg0,
+        value: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("proposeParameterChange")
+            .argument(&parameter)
+            .argument(&value)
+            .original_result()
+    }
+
+    pub fn vote_proposal<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<bool>,
+    >(
+        self,
+        proposal_id: Arg0,
+        support: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("voteProposal")
+            .argument(&proposal_id)
+            .argument(&support)
+            .original_result()
+    }
+
+    pub fn execute_proposal<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        proposal_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("executeProposal")
+            .argument(&proposal_id)
+            .original_result()
+    }
+
+    pub fn set_governance_params<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<u64>,
+        Arg2: ProxyArg<BigUint<Env::Api>>,
+        Arg3: ProxyArg<BigUint<Env::Api>>,
+        Arg4: ProxyArg<u64>,
+    >(
+        self,
+        voting_period: Arg0,
+        timelock: Arg1,
+        quorum: Arg2,
+        proposal_min_stake: Arg3,
+        proposal_min_reputation: Arg4,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setGovernanceParams")
+            .argument(&voting_period)
+            .argument(&timelock)
+            .argument(&quorum)
+            .argument(&proposal_min_stake)
+            .argument(&proposal_min_reputation)
+            .original_result()
+    }
+
+    pub fn get_min_bounty(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getMinBounty")
+            .original_result()
+    }
+
+    pub fn get_deadline_bounds(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValue2<u64, u64>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getDeadlineBounds")
+            .original_result()
+    }
+
+    pub fn get_proposal<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        proposal_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, OptionalValue<Proposal<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getProposal")
+            .argument(&proposal_id)
+            .original_result()
+    }
+
+    pub fn get_proposal_status<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        proposal_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ProposalStatus> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getProposalStatus")
+            .argument(&proposal_id)
+            .original_result()
+    }
+
+    pub fn get_active_proposals(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, Proposal<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getActiveProposals")
+            .original_result()
+    }
+
+    pub fn get_proposal_votes<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        proposal_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValue2<BigUint<Env::Api>, BigUint<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getProposalVotes")
+            .argument(&proposal_id)
+            .original_result()
+    }
+
+    pub fn issue_badge_collection<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        token_display_name: Arg0,
+        token_ticker: Arg1,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+        self.wrapped_tx
+            .raw_call("issueBadgeCollection")
+            .argument(&token_display_name)
+            .argument(&token_ticker)
+            .original_result()
+    }
+
+    pub fn add_badge<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<BadgeCriterion>,
+        Arg2: ProxyArg<u64>,
+        Arg3: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        name: Arg0,
+        criterion: Arg1,
+        threshold: Arg2,
+        uri: Arg3,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("addBadge")
+            .argument(&name)
+            .argument(&criterion)
+            .argument(&threshold)
+            .argument(&uri)
+            .original_result()
+    }
+
+    pub fn award_badge<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        badge_id: Arg0,
+        recipient: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("awardBadge")
+            .argument(&badge_id)
+            .argument(&recipient)
+            .original_result()
+    }
+
+    pub fn claim_badges(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("claimBadges")
+            .original_result()
+    }
+
+    pub fn get_pending_badges<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        address: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, u64>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getPendingBadges")
+            .argument(&address)
+            .original_result()
+    }
+
+    pub fn get_badges<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        address: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, Badge<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getBadges")
+            .argument(&address)
+            .original_result()
+    }
+
+    pub fn get_badge_registry(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, Badge<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getBadgeRegistry")
+            .original_result()
+    }
+
+    pub fn get_contribution_stats<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        address: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValue3<u64, u64, u64>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getContributionStats")
+            .argument(&address)
+            .original_result()
+    }
+
+    pub fn get_claimable<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        address: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, MultiValue2<EgldOrEsdtTokenIdentifier<Env::Api>, BigUint<Env::Api>>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getClaimable")
+            .argument(&address)
+            .original_result()
+    }
+
+    pub fn is_pull_payouts_enabled<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        address: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("isPullPayoutsEnabled")
+            .argument(&address)
+            .original_result()
+    }
+
+    pub fn get_answer_stake<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        question_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getAnswerStake")
+            .argument(&question_id)
+            .original_result()
+    }
+
+    pub fn get_answer_stake_deposit<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        answer_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getAnswerStakeDeposit")
+            .argument(&answer_id)
+            .original_result()
+    }
+
+    pub fn is_answer_spam<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        answer_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("isAnswerSpam")
+            .argument(&answer_id)
+            .original_result()
+    }
+
+    pub fn get_reveal_period<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        question_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getRevealPeriod")
+            .argument(&question_id)
+            .original_result()
+    }
+
+    pub fn compute_answer_commitment<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg2: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg3: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        answerer: Arg0,
+        title: Arg1,
+        description: Arg2,
+        salt: Arg3,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedByteArray<Env::Api, 32usize>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("computeAnswerCommitment")
+            .argument(&answerer)
+            .argument(&title)
+            .argument(&description)
+            .argument(&salt)
+            .original_result()
+    }
+
+    pub fn is_answer_revealed<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        answer_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("isAnswerRevealed")
+            .argument(&answer_id)
+            .original_result()
+    }
+
+    pub fn compute_content_hash<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        title: Arg0,
+        description: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedByteArray<Env::Api, 32usize>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("computeContentHash")
+            .argument(&title)
+            .argument(&description)
+            .original_result()
+    }
+
+    pub fn get_answer_content_hash<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        answer_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, OptionalValue<ManagedByteArray<Env::Api, 32usize>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getAnswerContentHash")
+            .argument(&answer_id)
+            .original_result()
+    }
+
+    pub fn get_content_hash_first_author<
+        Arg0: ProxyArg<ManagedByteArray<Env::Api, 32usize>>,
+    >(
+        self,
+        content_hash: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, OptionalValue<MultiValue3<u64, ManagedAddress<Env::Api>, u64>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getContentHashFirstAuthor")
+            .argument(&content_hash)
+            .original_result()
+    }
+
+    pub fn get_treasury(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedAddress<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getTreasury")
+            .original_result()
+    }
+
+    pub fn get_all_open_questions(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, Question<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getAllOpenQuestions")
+            .original_result()
+    }
+
+    pub fn get_question_details<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        question_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, OptionalValue<Question<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getQuestionDetails")
+            .argument(&question_id)
+            .original_result()
+    }
+
+    pub fn get_answers_for_question<
+        Arg0: ProxyArg<u64>,
     >(
         self,
         question_id: Arg0,
-        title: Arg1,
-        description: Arg2,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, Answer<Env::Api>>> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("submitAnswer")
+            .raw_call("getAnswersForQuestion")
             .argument(&question_id)
-            .argument(&title)
-            .argument(&description)
             .original_result()
     }
 
-    pub fn approve_answer<
+    pub fn pause_contract(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("pauseContract")
+            .original_result()
+    }
+
+    pub fn unpause_contract(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("unpauseContract")
+            .original_result()
+    }
+
+    pub fn emergency_refund_batch<
         Arg0: ProxyArg<u64>,
-        Arg1: ProxyArg<u64>,
     >(
         self,
-        question_id: Arg0,
-        answer_id: Arg1,
+        max_items: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("emergencyRefundBatch")
+            .argument(&max_items)
+            .original_result()
+    }
+
+    pub fn get_emergency_refund_progress(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValue2<u64, bool>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getEmergencyRefundProgress")
+            .original_result()
+    }
+
+    pub fn queue_admin_action<
+        Arg0: ProxyArg<AdminAction<Env::Api>>,
+    >(
+        self,
+        action: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("queueAdminAction")
+            .argument(&action)
+            .original_result()
+    }
+
+    pub fn execute_admin_action<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        action_id: Arg0,
     ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("approveAnswer")
-            .argument(&question_id)
-            .argument(&answer_id)
+            .raw_call("executeAdminAction")
+            .argument(&action_id)
             .original_result()
     }
 
-    pub fn refund_question<
+    pub fn cancel_admin_action<
         Arg0: ProxyArg<u64>,
     >(
         self,
-        question_id: Arg0,
+        action_id: Arg0,
     ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("refundQuestion")
-            .argument(&question_id)
+            .raw_call("cancelAdminAction")
+            .argument(&action_id)
             .original_result()
     }
 
-    pub fn get_all_open_questions(
+    pub fn get_pending_admin_actions(
         self,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, Question<Env::Api>>> {
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, QueuedAdminAction<Env::Api>>> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("getAllOpenQuestions")
+            .raw_call("getPendingAdminActions")
             .original_result()
     }
 
-    pub fn get_question_details<
-        Arg0: ProxyArg<u64>,
+    pub fn get_admin_timelock_delay(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getAdminTimelockDelay")
+            .original_result()
+    }
+
+    pub fn add_to_denylist<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
     >(
         self,
-        question_id: Arg0,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, OptionalValue<Question<Env::Api>>> {
+        address: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("getQuestionDetails")
-            .argument(&question_id)
+            .raw_call("addToDenylist")
+            .argument(&address)
             .original_result()
     }
 
-    pub fn get_answers_for_question<
+    pub fn remove_from_denylist<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        address: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("removeFromDenylist")
+            .argument(&address)
+            .original_result()
+    }
+
+    pub fn set_user_limits<
         Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<u32>,
+        Arg2: ProxyArg<u64>,
     >(
         self,
-        question_id: Arg0,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, Answer<Env::Api>>> {
+        max_open_questions: Arg0,
+        max_answers_per_question: Arg1,
+        post_cooldown: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("getAnswersForQuestion")
-            .argument(&question_id)
+            .raw_call("setUserLimits")
+            .argument(&max_open_questions)
+            .argument(&max_answers_per_question)
+            .argument(&post_cooldown)
+            .original_result()
+    }
+
+    pub fn get_moderators(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, ManagedAddress<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getModerators")
             .original_result()
     }
 
-    pub fn get_questions_by_status<
-        Arg0: ProxyArg<QuestionStatus>,
+    pub fn is_moderator<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
     >(
         self,
-        status: Arg0,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, Question<Env::Api>>> {
+        address: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("getQuestionsByStatus")
-            .argument(&status)
+            .raw_call("isModerator")
+            .argument(&address)
             .original_result()
     }
 
-    pub fn get_expired_questions(
+    pub fn is_denylisted<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
         self,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, Question<Env::Api>>> {
+        address: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("isDenylisted")
+            .argument(&address)
+            .original_result()
+    }
+
+    pub fn get_user_limits(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValue3<u64, u32, u64>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getUserLimits")
+            .original_result()
+    }
+
+    pub fn get_open_question_count<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        address: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getOpenQuestionCount")
+            .argument(&address)
+            .original_result()
+    }
+
+    pub fn get_owner(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedAddress<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getOwner")
+            .original_result()
+    }
+
+    pub fn is_contract_paused(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("isPaused")
+            .original_result()
+    }
+
+    pub fn get_total_questions(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getTotalQuestions")
+            .original_result()
+    }
+
+    pub fn get_total_answers(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getTotalAnswers")
+            .original_result()
+    }
+
+    pub fn get_contract_stats(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValue6<u64, u64, bool, BigUint<Env::Api>, BigUint<Env::Api>, BigUint<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getContractStats")
+            .original_result()
+    }
+
+    pub fn get_token_totals<
+        Arg0: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+    >(
+        self,
+        token: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValue3<BigUint<Env::Api>, BigUint<Env::Api>, BigUint<Env::Api>>> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("getExpiredQuestions")
+            .raw_call("getTokenTotals")
+            .argument(&token)
             .original_result()
     }
+
+    pub fn check_solvency<
+        Arg0: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+    >(
+        self,
+        token: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValue3<BigUint<Env::Api>, BigUint<Env::Api>, bool>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("checkSolvency")
+            .argument(&token)
+            .original_result()
+    }
+}
+
+#[rustfmt::skip]
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Debug)]
+pub enum DecayCurve {
+    Linear {
+        duration: u64,
+        min_payout_bps: u64,
+    },
+    Stepwise {
+        step_duration: u64,
+        step_bps: u64,
+        min_payout_bps: u64,
+    },
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Debug)]
+pub enum CommentTarget {
+    Question(u64),
+    Answer(u64),
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Debug)]
+pub struct Comment<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub comment_id: u64,
+    pub author: ManagedAddress<Api>,
+    pub target: CommentTarget,
+    pub reply_to: Option<u64>,
+    pub content: ManagedBuffer<Api>,
+    pub created_at: u64,
+    pub hidden: bool,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Debug)]
+pub struct Profile<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub display_name: ManagedBuffer<Api>,
+    pub bio_uri: ManagedBuffer<Api>,
+    pub skills: ManagedVec<Api, ManagedBuffer<Api>>,
+    pub updated_at: u64,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Debug)]
+pub struct SponsorPool<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub pool_id: u64,
+    pub sponsor: ManagedAddress<Api>,
+    pub tag: ManagedBuffer<Api>,
+    pub balance: BigUint<Api>,
+    pub match_amount: BigUint<Api>,
+    pub match_ratio_bps: u64,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Debug)]
+pub struct SponsorMatch<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub pool_id: u64,
+    pub question_id: u64,
+    pub amount: BigUint<Api>,
+    pub timestamp: u64,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Debug)]
+pub struct Vesting<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub beneficiary: ManagedAddress<Api>,
+    pub total_amount: BigUint<Api>,
+    pub claimed_amount: BigUint<Api>,
+    pub start: u64,
+    pub end: u64,
+    pub frozen_at: u64,
+    pub dispute_resolved: bool,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Debug)]
+pub struct Milestone<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub milestone_id: usize,
+    pub name: ManagedBuffer<Api>,
+    pub amount: BigUint<Api>,
+    pub status: MilestoneStatus,
+    pub answer_id: Option<u64>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Debug)]
+pub enum MilestoneStatus {
+    Pending,
+    Approved,
+    Refunded,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Debug)]
+pub struct JobAssignment<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub assignee: ManagedAddress<Api>,
+    pub review_window: u64,
+    pub accepted_at: u64,
+    pub delivered_at: u64,
+    pub answer_id: Option<u64>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Debug)]
+pub enum GovernanceParameter {
+    MinBounty,
+    PlatformFee,
+    MinDeadlineDuration,
+    MaxDeadlineDuration,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct Proposal<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub proposal_id: u64,
+    pub proposer: ManagedAddress<Api>,
+    pub parameter: GovernanceParameter,
+    pub value: BigUint<Api>,
+    pub created_at: u64,
+    pub voting_ends_at: u64,
+    pub votes_for: BigUint<Api>,
+    pub votes_against: BigUint<Api>,
+    pub executed: bool,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Debug)]
+pub enum ProposalStatus {
+    Voting,
+    Defeated,
+    Timelocked,
+    Executable,
+    Executed,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Debug)]
+pub enum BadgeCriterion {
+    ApprovedAnswers,
+    VotesReceived,
+    VotesCast,
+    Manual,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct Badge<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub badge_id: u64,
+    pub name: ManagedBuffer<Api>,
+    pub criterion: BadgeCriterion,
+    pub threshold: u64,
+    pub uri: ManagedBuffer<Api>,
 }
 
 #[type_abi]
@@ -201,6 +2192,7 @@ where
     pub created_at: u64,
     pub status: QuestionStatus,
     pub approved_answer_id: Option<u64>,
+    pub duplicate_of: Option<u64>,
 }
 
 #[type_abi]
@@ -210,6 +2202,12 @@ pub enum QuestionStatus {
     Answered,
     AnswerApproved,
     Expired,
+    EmergencyRefunded,
+    Assigned,
+    InProgress,
+    Delivered,
+    Disputed,
+    Duplicate,
 }
 
 #[type_abi]
@@ -228,6 +2226,35 @@ where
     pub approved_by_creator: bool,
 }
 
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Debug)]
+pub enum AdminAction<Api>
+where
+    Api: ManagedTypeApi,
+{
+    TransferOwnership(ManagedAddress<Api>),
+    SetPlatformFee(u64),
+    SetFeeDiscount(BigUint<Api>, u64),
+    SetTreasury(ManagedAddress<Api>),
+    AddModerator(ManagedAddress<Api>),
+    RemoveModerator(ManagedAddress<Api>),
+    AddToDenylist(ManagedAddress<Api>),
+    RemoveFromDenylist(ManagedAddress<Api>),
+    SetTimelockDelay(u64),
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct QueuedAdminAction<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub action_id: u64,
+    pub action: AdminAction<Api>,
+    pub queued_at: u64,
+    pub executable_at: u64,
+}
+
 #[type_abi]
 #[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
 pub struct QuestionCreatedEventData<Api>
@@ -1,4 +1,9 @@
-use stacktoken::stacktoken_proxy;
+use stacktoken::{
+    stacktoken_proxy, StackTokenContract, MAX_POOLS_PER_TAG, MAX_REVEAL_PERIOD, MAX_REVIEW_WINDOW,
+    MAX_VESTING_PERIOD, MIN_ADMIN_TIMELOCK_DELAY, MIN_EGLD_LOCKED, MIN_GOVERNANCE_TIMELOCK, MIN_UNBONDING_PERIOD,
+    MIN_VOTING_PERIOD,
+};
+use stacktoken_proxy::AdminAction;
 use multiversx_sc_scenario::imports::*;
 
 const CODE_PATH: MxscPath = MxscPath::new("output/stacktoken.mxsc.json");
@@ -12,12 +17,23 @@ fn world() -> ScenarioWorld {
 }
 
 const OWNER: TestAddress = TestAddress::new("owner");
+const ASKER: TestAddress = TestAddress::new("asker");
+const ANSWERER: TestAddress = TestAddress::new("answerer");
+const SPAMMER: TestAddress = TestAddress::new("spammer");
+const TREASURY: TestAddress = TestAddress::new("treasury");
+const STAKER: TestAddress = TestAddress::new("staker");
+const STACK_TOKEN_ID: TestTokenIdentifier = TestTokenIdentifier::new("STACK-123456");
+const BADGE_TOKEN_ID: TestTokenIdentifier = TestTokenIdentifier::new("BADGE-123456");
 const STACKTOKEN_ADDRESS: TestSCAddress = TestSCAddress::new("stacktoken");
 
 fn stacktoken_deploy() -> ScenarioWorld {
     let mut world = world();
 
-    world.account(OWNER).nonce(0).balance(1_000_000);
+    world.account(OWNER).nonce(0).balance(10 * MIN_EGLD_LOCKED);
+    world.account(ASKER).nonce(0).balance(10 * MIN_EGLD_LOCKED);
+    world.account(ANSWERER).nonce(0).balance(10 * MIN_EGLD_LOCKED);
+    world.account(SPAMMER).nonce(0).balance(10 * MIN_EGLD_LOCKED);
+    world.account(TREASURY).nonce(0).balance(0);
 
     let stacktoken_address = world
         .tx()
@@ -56,4 +72,2419 @@ fn stacktoken_deploy_and_basic_query_test() {
         .run();
     
     // If we reach this point, the query was successful
-}
\ No newline at end of file
+}
+
+fn post_question(world: &mut ScenarioWorld, deadline: u64) {
+    post_tagged_question(world, deadline, &[]);
+}
+
+fn post_tagged_question(world: &mut ScenarioWorld, deadline: u64, tags: &[&str]) {
+    let tags: MultiValueEncoded<StaticApi, ManagedBuffer<StaticApi>> =
+        tags.iter().map(|tag| ManagedBuffer::from(*tag)).collect();
+    world
+        .tx()
+        .from(ASKER)
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .post_question("title", "description", deadline, tags)
+        .egld(MIN_EGLD_LOCKED)
+        .run();
+}
+
+// Stands in for the async issuance, which the test VM doesn't run to completion
+fn set_stack_token(world: &mut ScenarioWorld) {
+    world
+        .tx()
+        .from(OWNER)
+        .to(STACKTOKEN_ADDRESS)
+        .whitebox(stacktoken::contract_obj, |sc| {
+            sc.stack_token()
+                .set_token_id(STACK_TOKEN_ID.to_token_identifier());
+        });
+}
+
+// Queues and executes an admin action, stepping over the timelock and then winding the
+// clock back so the calling test keeps its own timeline
+fn run_admin_action(world: &mut ScenarioWorld, action: AdminAction<StaticApi>) {
+    let action_id = world
+        .tx()
+        .from(OWNER)
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .queue_admin_action(action)
+        .returns(ReturnsResult)
+        .run();
+    let queued = world
+        .query()
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .get_pending_admin_actions()
+        .returns(ReturnsResult)
+        .run()
+        .into_iter()
+        .find(|queued| queued.action_id == action_id)
+        .unwrap();
+
+    world.current_block().block_timestamp(queued.executable_at);
+    world
+        .tx()
+        .from(OWNER)
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .execute_admin_action(action_id)
+        .run();
+    world.current_block().block_timestamp(queued.queued_at);
+}
+
+#[test]
+fn denylisted_address_cannot_post_or_answer_test() {
+    let mut world = stacktoken_deploy();
+    post_question(&mut world, 1_000);
+
+    run_admin_action(&mut world, AdminAction::AddToDenylist(ASKER.to_address().into()));
+    run_admin_action(&mut world, AdminAction::AddToDenylist(ANSWERER.to_address().into()));
+
+    world
+        .tx()
+        .from(ASKER)
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .post_question("title", "description", 1_000u64, MultiValueEncoded::<StaticApi, ManagedBuffer<StaticApi>>::new())
+        .egld(MIN_EGLD_LOCKED)
+        .returns(ExpectError(4, "Address is denylisted"))
+        .run();
+    world
+        .tx()
+        .from(ANSWERER)
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .submit_answer(1u64, "title", "description")
+        .returns(ExpectError(4, "Address is denylisted"))
+        .run();
+}
+
+#[test]
+fn only_moderator_can_denylist_directly_test() {
+    let mut world = stacktoken_deploy();
+
+    for user in [ASKER, OWNER] {
+        world
+            .tx()
+            .from(user)
+            .to(STACKTOKEN_ADDRESS)
+            .typed(stacktoken_proxy::StackTokenContractProxy)
+            .add_to_denylist(ANSWERER)
+            .returns(ExpectError(4, "Only moderator"))
+            .run();
+    }
+
+    run_admin_action(&mut world, AdminAction::AddModerator(ASKER.to_address().into()));
+    world
+        .tx()
+        .from(ASKER)
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .add_to_denylist(ANSWERER)
+        .run();
+
+    world
+        .query()
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .is_denylisted(ANSWERER)
+        .returns(ExpectValue(true))
+        .run();
+}
+
+#[test]
+fn user_limits_test() {
+    let mut world = stacktoken_deploy();
+
+    world
+        .tx()
+        .from(OWNER)
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .set_user_limits(1u64, 1u32, 60u64)
+        .run();
+
+    post_question(&mut world, 1_000);
+    world.current_block().block_timestamp(100);
+    world
+        .tx()
+        .from(ASKER)
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .post_question("title", "description", 1_000u64, MultiValueEncoded::<StaticApi, ManagedBuffer<StaticApi>>::new())
+        .egld(MIN_EGLD_LOCKED)
+        .returns(ExpectError(4, "Too many open questions"))
+        .run();
+
+    world
+        .tx()
+        .from(ANSWERER)
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .submit_answer(1u64, "title", "description")
+        .run();
+    world.current_block().block_timestamp(200);
+    world
+        .tx()
+        .from(ANSWERER)
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .submit_answer(1u64, "title", "description")
+        .returns(ExpectError(4, "Too many answers for this question"))
+        .run();
+}
+
+#[test]
+fn answer_stake_is_returned_or_slashed_test() {
+    let mut world = stacktoken_deploy();
+    let stake = MIN_EGLD_LOCKED / 10;
+    post_question(&mut world, 1_000);
+
+    run_admin_action(&mut world, AdminAction::SetTreasury(TREASURY.to_address().into()));
+    world
+        .tx()
+        .from(ASKER)
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .set_answer_stake(1u64, stake)
+        .run();
+
+    world
+        .tx()
+        .from(ANSWERER)
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .submit_answer(1u64, "title", "description")
+        .returns(ExpectError(4, "Invalid answer stake"))
+        .run();
+    world
+        .tx()
+        .from(ANSWERER)
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .submit_answer(1u64, "title", "description")
+        .egld(stake)
+        .run();
+    world
+        .tx()
+        .from(SPAMMER)
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .submit_answer(1u64, "junk", "junk")
+        .egld(stake)
+        .run();
+
+    world
+        .tx()
+        .from(ASKER)
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .mark_answer_as_spam(2u64)
+        .run();
+    world.check_account(TREASURY).balance(stake);
+
+    world
+        .tx()
+        .from(ASKER)
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .approve_answer(1u64, 1u64)
+        .run();
+    world
+        .check_account(ANSWERER)
+        .balance(11 * MIN_EGLD_LOCKED);
+    world
+        .check_account(SPAMMER)
+        .balance(10 * MIN_EGLD_LOCKED - stake);
+}
+
+#[test]
+fn commit_reveal_answer_test() {
+    let mut world = stacktoken_deploy();
+    post_question(&mut world, 1_000);
+
+    world
+        .tx()
+        .from(ASKER)
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .enable_commit_reveal(1u64, MAX_REVEAL_PERIOD + 1)
+        .returns(ExpectError(4, "Reveal period too long"))
+        .run();
+    world
+        .tx()
+        .from(ASKER)
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .enable_commit_reveal(1u64, 500u64)
+        .run();
+
+    let commitment = world
+        .query()
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .compute_answer_commitment(ANSWERER, "title", "description", "salt")
+        .returns(ReturnsResult)
+        .run();
+
+    world
+        .tx()
+        .from(ANSWERER)
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .submit_answer(1u64, "title", "description")
+        .returns(ExpectError(4, "Question requires commit-reveal answers"))
+        .run();
+    world
+        .tx()
+        .from(ANSWERER)
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .commit_answer(1u64, commitment)
+        .run();
+
+    world
+        .tx()
+        .from(ANSWERER)
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .reveal_answer(1u64, "title", "description", "salt")
+        .returns(ExpectError(4, "Reveal window not open"))
+        .run();
+
+    world.current_block().block_timestamp(1_100);
+    world
+        .tx()
+        .from(ANSWERER)
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .reveal_answer(1u64, "copied", "description", "salt")
+        .returns(ExpectError(4, "Commitment mismatch"))
+        .run();
+    world
+        .tx()
+        .from(ANSWERER)
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .reveal_answer(1u64, "title", "description", "salt")
+        .run();
+
+    world
+        .tx()
+        .from(ASKER)
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .approve_answer(1u64, 1u64)
+        .run();
+    world
+        .check_account(ANSWERER)
+        .balance(11 * MIN_EGLD_LOCKED);
+}
+
+#[test]
+fn duplicate_answer_is_rejected_test() {
+    let mut world = stacktoken_deploy();
+    post_question(&mut world, 1_000);
+
+    world
+        .tx()
+        .from(ANSWERER)
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .submit_answer(1u64, "Use a Mapper", "Store it  in a\nSetMapper")
+        .run();
+    world
+        .tx()
+        .from(SPAMMER)
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .submit_answer(1u64, "use a mapper ", "store it in a setmapper")
+        .returns(ExpectError(4, "Duplicate answer"))
+        .run();
+
+    let content_hash = world
+        .query()
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .compute_content_hash("use a mapper", "store it in a setmapper")
+        .returns(ReturnsResult)
+        .run();
+    world
+        .query()
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .get_content_hash_first_author(content_hash)
+        .returns(ExpectValue(OptionalValue::Some(
+            (1u64, ManagedAddress::from(ANSWERER.to_address()), 0u64).into(),
+        )))
+        .run();
+}
+
+#[test]
+fn pull_payouts_are_claimable_test() {
+    let mut world = stacktoken_deploy();
+    post_question(&mut world, 1_000);
+    post_question(&mut world, 2_000);
+
+    world
+        .tx()
+        .from(ANSWERER)
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .set_pull_payouts(true)
+        .run();
+    world
+        .tx()
+        .from(ANSWERER)
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .submit_answer(2u64, "title", "description")
+        .run();
+    world
+        .tx()
+        .from(ASKER)
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .approve_answer(2u64, 1u64)
+        .run();
+    world
+        .check_account(ANSWERER)
+        .balance(10 * MIN_EGLD_LOCKED);
+
+    // Batch expiry credits the creator instead of sending directly
+    world.current_block().block_timestamp(1_000);
+    world
+        .tx()
+        .from(OWNER)
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .expire_questions()
+        .run();
+    world
+        .check_account(ASKER)
+        .balance(8 * MIN_EGLD_LOCKED);
+
+    for user in [ANSWERER, ASKER] {
+        world
+            .tx()
+            .from(user)
+            .to(STACKTOKEN_ADDRESS)
+            .typed(stacktoken_proxy::StackTokenContractProxy)
+            .claim()
+            .run();
+    }
+    world
+        .check_account(ANSWERER)
+        .balance(11 * MIN_EGLD_LOCKED);
+    world
+        .check_account(ASKER)
+        .balance(9 * MIN_EGLD_LOCKED);
+
+    world
+        .tx()
+        .from(ASKER)
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .claim()
+        .returns(ExpectError(4, "Nothing to claim"))
+        .run();
+}
+
+#[test]
+fn stack_token_rewards_config_test() {
+    let mut world = stacktoken_deploy();
+
+    world
+        .tx()
+        .from(OWNER)
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .set_stack_token_rewards(10u64, 20u64)
+        .returns(ExpectError(4, "Asker reward exceeds answerer reward"))
+        .run();
+    world
+        .tx()
+        .from(OWNER)
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .set_stack_token_rewards(20u64, 5u64)
+        .run();
+
+    // Approvals keep working before the token is issued; rewards are simply skipped
+    post_question(&mut world, 1_000);
+    world
+        .tx()
+        .from(ANSWERER)
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .submit_answer(1u64, "title", "description")
+        .run();
+    world
+        .tx()
+        .from(ASKER)
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .approve_answer(1u64, 1u64)
+        .run();
+
+    world
+        .query()
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .get_stack_token_id()
+        .returns(ExpectValue(OptionalValue::<TokenIdentifier<StaticApi>>::None))
+        .run();
+}
+
+#[test]
+fn vote_answer_test() {
+    let mut world = stacktoken_deploy();
+    post_question(&mut world, 1_000);
+
+    world
+        .tx()
+        .from(ANSWERER)
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .submit_answer(1u64, "title", "description")
+        .run();
+
+    world
+        .tx()
+        .from(ANSWERER)
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .vote_answer(1u64)
+        .returns(ExpectError(4, "Cannot vote own answer"))
+        .run();
+    world
+        .tx()
+        .from(ASKER)
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .vote_answer(1u64)
+        .run();
+    world
+        .tx()
+        .from(ASKER)
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .vote_answer(1u64)
+        .returns(ExpectError(4, "Already voted"))
+        .run();
+
+    world
+        .query()
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .get_contribution_stats(ANSWERER)
+        .returns(ExpectValue(MultiValue3::from((0u64, 1u64, 0u64))))
+        .run();
+    world
+        .query()
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .get_contribution_stats(ASKER)
+        .returns(ExpectValue(MultiValue3::from((0u64, 0u64, 1u64))))
+        .run();
+}
+
+#[test]
+fn badge_registry_test() {
+    let mut world = stacktoken_deploy();
+
+    world
+        .tx()
+        .from(OWNER)
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .add_badge("First approval", stacktoken_proxy::BadgeCriterion::ApprovedAnswers, 0u64, "")
+        .returns(ExpectError(4, "Invalid badge threshold"))
+        .run();
+    world
+        .tx()
+        .from(OWNER)
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .add_badge("First approval", stacktoken_proxy::BadgeCriterion::ApprovedAnswers, 1u64, "")
+        .returns(ExpectValue(1u64))
+        .run();
+
+    world
+        .tx()
+        .from(OWNER)
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .award_badge(1u64, ANSWERER)
+        .returns(ExpectError(4, "Badge collection not issued"))
+        .run();
+
+    // Stands in for the async collection issuance
+    world.set_esdt_local_roles(STACKTOKEN_ADDRESS, BADGE_TOKEN_ID.as_bytes(), &[EsdtLocalRole::NftCreate]);
+    world
+        .tx()
+        .from(OWNER)
+        .to(STACKTOKEN_ADDRESS)
+        .whitebox(stacktoken::contract_obj, |sc| {
+            sc.badge_nft().set_token_id(BADGE_TOKEN_ID.to_token_identifier());
+        });
+
+    // Pull-payout users get their badge credited instead of pushed
+    world
+        .tx()
+        .from(ANSWERER)
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .set_pull_payouts(true)
+        .run();
+    world
+        .tx()
+        .from(OWNER)
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .award_badge(1u64, ANSWERER)
+        .run();
+    world
+        .query()
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .get_pending_badges(ANSWERER)
+        .returns(ExpectValue(MultiValueVec::from(vec![1u64])))
+        .run();
+    world
+        .tx()
+        .from(ANSWERER)
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .claim_badges()
+        .run();
+    world
+        .check_account(ANSWERER)
+        .esdt_nft_balance_and_attributes(BADGE_TOKEN_ID, 1, 1, 1u64);
+    world
+        .tx()
+        .from(ANSWERER)
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .claim_badges()
+        .returns(ExpectError(4, "No badges to claim"))
+        .run();
+}
+
+#[test]
+fn stake_weights_votes_and_unbonds_test() {
+    let mut world = stacktoken_deploy();
+    world
+        .account(STAKER)
+        .nonce(0)
+        .balance(0)
+        .esdt_balance(STACK_TOKEN_ID, 1_000);
+    set_stack_token(&mut world);
+
+    world
+        .tx()
+        .from(OWNER)
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .set_vote_weight_params(100u64, 5u32)
+        .run();
+    world
+        .tx()
+        .from(OWNER)
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .set_unbonding_period(0u64)
+        .returns(ExpectError(4, "Unbonding period too short"))
+        .run();
+
+    world
+        .tx()
+        .from(STAKER)
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .stake()
+        .single_esdt(&STACK_TOKEN_ID.into(), 0, &BigUint::from(300u64))
+        .run();
+    world
+        .query()
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .get_vote_weight(STAKER)
+        .returns(ExpectValue(4u32))
+        .run();
+
+    post_question(&mut world, 1_000);
+    world
+        .tx()
+        .from(ANSWERER)
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .submit_answer(1u64, "title", "description")
+        .run();
+    world
+        .tx()
+        .from(STAKER)
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .vote_answer(1u64)
+        .run();
+    world
+        .query()
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .get_contribution_stats(ANSWERER)
+        .returns(ExpectValue(MultiValue3::from((0u64, 4u64, 0u64))))
+        .run();
+
+    world
+        .tx()
+        .from(STAKER)
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .unstake(100u64)
+        .run();
+    // Staked and unbonding tokens are both owed back to the staker
+    world
+        .query()
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .check_solvency(EgldOrEsdtTokenIdentifier::esdt(STACK_TOKEN_ID))
+        .returns(ExpectValue(MultiValue3::from((
+            BigUint::<StaticApi>::from(300u64),
+            BigUint::<StaticApi>::from(300u64),
+            true,
+        ))))
+        .run();
+    world
+        .tx()
+        .from(STAKER)
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .unstake(200u64)
+        .run();
+    world
+        .tx()
+        .from(STAKER)
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .withdraw_unstaked()
+        .returns(ExpectError(4, "Unbonding period not over"))
+        .run();
+
+    // The default unbonding period applies without any owner setup
+    world.current_block().block_timestamp(MIN_UNBONDING_PERIOD);
+    world
+        .tx()
+        .from(STAKER)
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .withdraw_unstaked()
+        .run();
+    world
+        .check_account(STAKER)
+        .esdt_balance(STACK_TOKEN_ID, 1_000);
+    world
+        .query()
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .get_total_staked()
+        .returns(ExpectValue(0u64))
+        .run();
+    world
+        .query()
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .check_solvency(EgldOrEsdtTokenIdentifier::esdt(STACK_TOKEN_ID))
+        .returns(ExpectValue(MultiValue3::from((
+            BigUint::<StaticApi>::zero(),
+            BigUint::<StaticApi>::zero(),
+            true,
+        ))))
+        .run();
+}
+
+#[test]
+fn platform_fee_goes_to_treasury_test() {
+    let mut world = stacktoken_deploy();
+
+    run_admin_action(&mut world, AdminAction::SetTreasury(TREASURY.to_address().into()));
+    run_admin_action(&mut world, AdminAction::SetPlatformFee(500));
+
+    post_question(&mut world, 1_000);
+    world
+        .tx()
+        .from(ANSWERER)
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .submit_answer(1u64, "title", "description")
+        .run();
+    world
+        .tx()
+        .from(ASKER)
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .approve_answer(1u64, 1u64)
+        .run();
+
+    let fee = MIN_EGLD_LOCKED / 20;
+    world.check_account(TREASURY).balance(fee);
+    world
+        .check_account(ANSWERER)
+        .balance(11 * MIN_EGLD_LOCKED - fee);
+}
+
+#[test]
+fn governance_proposal_changes_platform_fee_test() {
+    let mut world = stacktoken_deploy();
+    world
+        .account(STAKER)
+        .nonce(0)
+        .balance(0)
+        .esdt_balance(STACK_TOKEN_ID, 1_000);
+    set_stack_token(&mut world);
+
+    world
+        .tx()
+        .from(OWNER)
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .set_governance_params(1u64, 0u64, 0u64, 100u64, 0u64)
+        .returns(ExpectError(4, "Voting period too short"))
+        .run();
+    world
+        .tx()
+        .from(OWNER)
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .set_governance_params(MIN_VOTING_PERIOD, 0u64, 500u64, 100u64, 0u64)
+        .returns(ExpectError(4, "Governance timelock too short"))
+        .run();
+    world
+        .tx()
+        .from(OWNER)
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .set_governance_params(MIN_VOTING_PERIOD, MIN_GOVERNANCE_TIMELOCK, 500u64, 100u64, 0u64)
+        .run();
+
+    world
+        .tx()
+        .from(ASKER)
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .propose_parameter_change(stacktoken_proxy::GovernanceParameter::PlatformFee, 300u64)
+        .returns(ExpectError(4, "Not allowed to propose"))
+        .run();
+
+    world
+        .tx()
+        .from(STAKER)
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .stake()
+        .single_esdt(&STACK_TOKEN_ID.into(), 0, &BigUint::from(600u64))
+        .run();
+    world
+        .tx()
+        .from(STAKER)
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .propose_parameter_change(stacktoken_proxy::GovernanceParameter::PlatformFee, 300u64)
+        .returns(ExpectValue(1u64))
+        .run();
+    world
+        .tx()
+        .from(STAKER)
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .vote_proposal(1u64, true)
+        .run();
+    // Voted stake cannot be moved to another address and voted again
+    world
+        .tx()
+        .from(STAKER)
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .unstake(600u64)
+        .returns(ExpectError(4, "Stake locked by active vote"))
+        .run();
+
+    world.current_block().block_timestamp(MIN_VOTING_PERIOD + 20);
+    world
+        .query()
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .get_proposal_status(1u64)
+        .returns(ExpectValue(stacktoken_proxy::ProposalStatus::Timelocked))
+        .run();
+    world
+        .tx()
+        .from(ASKER)
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .execute_proposal(1u64)
+        .returns(ExpectError(4, "Proposal not executable"))
+        .run();
+
+    world.current_block().block_timestamp(MIN_VOTING_PERIOD + MIN_GOVERNANCE_TIMELOCK);
+    world
+        .tx()
+        .from(ASKER)
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .execute_proposal(1u64)
+        .run();
+    world
+        .query()
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .get_platform_fee()
+        .returns(ExpectValue(300u64))
+        .run();
+    world
+        .tx()
+        .from(STAKER)
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .unstake(600u64)
+        .run();
+}
+
+#[test]
+fn admin_actions_are_timelocked_test() {
+    let mut world = stacktoken_deploy();
+    world
+        .query()
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .get_admin_timelock_delay()
+        .returns(ExpectValue(MIN_ADMIN_TIMELOCK_DELAY))
+        .run();
+    world
+        .tx()
+        .from(OWNER)
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .queue_admin_action(AdminAction::SetTimelockDelay(0))
+        .returns(ExpectError(4, "Timelock delay too short"))
+        .run();
+
+    world
+        .tx()
+        .from(OWNER)
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .queue_admin_action(AdminAction::SetPlatformFee(200))
+        .returns(ExpectValue(1u64))
+        .run();
+    world
+        .tx()
+        .from(OWNER)
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .queue_admin_action(AdminAction::SetTreasury(TREASURY.to_address().into()))
+        .returns(ExpectValue(2u64))
+        .run();
+    world
+        .tx()
+        .from(OWNER)
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .queue_admin_action(AdminAction::TransferOwnership(ASKER.to_address().into()))
+        .returns(ExpectValue(3u64))
+        .run();
+
+    world
+        .tx()
+        .from(OWNER)
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .execute_admin_action(1u64)
+        .returns(ExpectError(4, "Admin action still timelocked"))
+        .run();
+    world
+        .tx()
+        .from(OWNER)
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .cancel_admin_action(2u64)
+        .run();
+
+    world.current_block().block_timestamp(MIN_ADMIN_TIMELOCK_DELAY);
+    for action_id in [1u64, 3] {
+        world
+            .tx()
+            .from(OWNER)
+            .to(STACKTOKEN_ADDRESS)
+            .typed(stacktoken_proxy::StackTokenContractProxy)
+            .execute_admin_action(action_id)
+            .run();
+    }
+    world
+        .tx()
+        .from(OWNER)
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .execute_admin_action(2u64)
+        .returns(ExpectError(4, "Admin action not pending"))
+        .run();
+
+    world
+        .query()
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .get_platform_fee()
+        .returns(ExpectValue(200u64))
+        .run();
+
+    // The reported owner changes, but owner-only endpoints still follow the protocol owner
+    world
+        .query()
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .get_owner()
+        .returns(ExpectValue(ASKER))
+        .run();
+    world
+        .tx()
+        .from(ASKER)
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .queue_admin_action(AdminAction::SetPlatformFee(300))
+        .returns(ExpectError(4, "Endpoint can only be called by owner"))
+        .run();
+    world
+        .tx()
+        .from(OWNER)
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .queue_admin_action(AdminAction::SetPlatformFee(300))
+        .returns(ExpectValue(4u64))
+        .run();
+}
+
+#[test]
+fn emergency_refund_batch_test() {
+    let mut world = stacktoken_deploy();
+    for deadline in [1_000u64, 2_000, 3_000] {
+        post_question(&mut world, deadline);
+    }
+    world
+        .tx()
+        .from(ANSWERER)
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .submit_answer(2u64, "title", "description")
+        .run();
+    world
+        .tx()
+        .from(ASKER)
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .approve_answer(2u64, 1u64)
+        .run();
+
+    world
+        .tx()
+        .from(OWNER)
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .emergency_refund_batch(2u64)
+        .returns(ExpectError(4, "Contract must be paused"))
+        .run();
+    world
+        .tx()
+        .from(OWNER)
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .pause_contract()
+        .run();
+
+    // The approved question is skipped, the open ones are refunded before their deadline
+    world
+        .tx()
+        .from(OWNER)
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .emergency_refund_batch(2u64)
+        .returns(ExpectValue(1u64))
+        .run();
+    world
+        .query()
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .get_emergency_refund_progress()
+        .returns(ExpectValue(MultiValue2::from((2u64, false))))
+        .run();
+    world
+        .tx()
+        .from(OWNER)
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .emergency_refund_batch(u64::MAX)
+        .returns(ExpectValue(1u64))
+        .run();
+    world
+        .tx()
+        .from(OWNER)
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .emergency_refund_batch(10u64)
+        .returns(ExpectError(4, "Emergency refund already finished"))
+        .run();
+
+    let question = world
+        .query()
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .get_question_details(3u64)
+        .returns(ReturnsResult)
+        .run();
+    let question = question.into_option().unwrap();
+    assert!(question.status == stacktoken_proxy::QuestionStatus::EmergencyRefunded);
+
+    world
+        .tx()
+        .from(ASKER)
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .claim()
+        .run();
+    world
+        .check_account(ASKER)
+        .balance(9 * MIN_EGLD_LOCKED);
+}
+
+#[test]
+fn solvency_totals_track_locked_paid_and_refunded_test() {
+    let mut world = stacktoken_deploy();
+    post_question(&mut world, 1_000);
+    post_question(&mut world, 2_000);
+
+    world
+        .tx()
+        .from(ANSWERER)
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .submit_answer(1u64, "title", "description")
+        .run();
+    world
+        .tx()
+        .from(ASKER)
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .approve_answer(1u64, 1u64)
+        .run();
+
+    // Batch expiry leaves the refund as an unclaimed balance, which still counts as owed
+    world.current_block().block_timestamp(2_000);
+    world
+        .tx()
+        .from(OWNER)
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .expire_questions()
+        .run();
+
+    world
+        .query()
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .get_contract_stats()
+        .returns(ExpectValue(MultiValue6::from((
+            2u64,
+            1u64,
+            false,
+            BigUint::<StaticApi>::zero(),
+            BigUint::<StaticApi>::from(MIN_EGLD_LOCKED),
+            BigUint::<StaticApi>::from(MIN_EGLD_LOCKED),
+        ))))
+        .run();
+    world
+        .query()
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .check_solvency(EgldOrEsdtTokenIdentifier::egld())
+        .returns(ExpectValue(MultiValue3::from((
+            BigUint::<StaticApi>::from(MIN_EGLD_LOCKED),
+            BigUint::<StaticApi>::from(MIN_EGLD_LOCKED),
+            true,
+        ))))
+        .run();
+}
+
+#[test]
+fn tip_answer_test() {
+    let mut world = stacktoken_deploy();
+    post_question(&mut world, 1_000);
+    world
+        .tx()
+        .from(ANSWERER)
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .submit_answer(1u64, "title", "description")
+        .run();
+
+    world
+        .tx()
+        .from(ANSWERER)
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .tip_answer(1u64)
+        .egld(MIN_EGLD_LOCKED)
+        .returns(ExpectError(4, "Cannot tip own answer"))
+        .run();
+    for _ in 0..2 {
+        world
+            .tx()
+            .from(SPAMMER)
+            .to(STACKTOKEN_ADDRESS)
+            .typed(stacktoken_proxy::StackTokenContractProxy)
+            .tip_answer(1u64)
+            .egld(MIN_EGLD_LOCKED)
+            .run();
+    }
+
+    world
+        .check_account(ANSWERER)
+        .balance(12 * MIN_EGLD_LOCKED);
+    world
+        .query()
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .get_answer_tips(1u64)
+        .returns(ExpectValue(MultiValueVec::from(vec![MultiValue2::from((
+            EgldOrEsdtTokenIdentifier::<StaticApi>::egld(),
+            BigUint::<StaticApi>::from(2 * MIN_EGLD_LOCKED),
+        ))])))
+        .run();
+}
+
+#[test]
+fn sponsor_pool_tops_up_tagged_questions_test() {
+    let mut world = stacktoken_deploy();
+
+    world
+        .tx()
+        .from(SPAMMER)
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .create_sponsor_pool("sdk", MIN_EGLD_LOCKED, 5_000u64)
+        .egld(2 * MIN_EGLD_LOCKED)
+        .returns(ExpectError(4, "Set either a match amount or a match ratio"))
+        .run();
+    world
+        .tx()
+        .from(SPAMMER)
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .create_sponsor_pool("sdk", MIN_EGLD_LOCKED, 0u64)
+        .egld(MIN_EGLD_LOCKED - 1)
+        .returns(ExpectError(4, "Pool deposit too low"))
+        .run();
+    world
+        .tx()
+        .from(SPAMMER)
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .create_sponsor_pool("sdk", 1u64, 0u64)
+        .egld(2 * MIN_EGLD_LOCKED)
+        .returns(ExpectError(4, "Match amount too low"))
+        .run();
+    world
+        .tx()
+        .from(SPAMMER)
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .create_sponsor_pool("sdk", 0u64, 1u64)
+        .egld(2 * MIN_EGLD_LOCKED)
+        .returns(ExpectError(4, "Match ratio too low"))
+        .run();
+    world
+        .tx()
+        .from(SPAMMER)
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .create_sponsor_pool("sdk", MIN_EGLD_LOCKED, 0u64)
+        .egld(2 * MIN_EGLD_LOCKED)
+        .returns(ExpectValue(1u64))
+        .run();
+
+    // The pool covers two matches; the third tagged question gets nothing
+    post_tagged_question(&mut world, 1_000, &["sdk"]);
+    post_tagged_question(&mut world, 2_000, &["rust", "sdk"]);
+    post_tagged_question(&mut world, 2_000, &["sdk"]);
+
+    let question = world
+        .query()
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .get_question_details(2u64)
+        .returns(ReturnsResult)
+        .run()
+        .into_option()
+        .unwrap();
+    assert_eq!(question.locked_amount, BigUint::from(2 * MIN_EGLD_LOCKED));
+    let matches = world
+        .query()
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .get_pool_matches(1u64)
+        .returns(ReturnsResult)
+        .run();
+    assert_eq!(matches.len(), 2);
+    // The drained pool is no longer listed under its tag
+    let pools = world
+        .query()
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .get_pools_by_tag("sdk")
+        .returns(ReturnsResult)
+        .run();
+    assert_eq!(pools.len(), 0);
+
+    // Refunding a matched question puts the top-up back into the pool
+    world.current_block().block_timestamp(1_000);
+    world
+        .tx()
+        .from(ASKER)
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .refund_question(1u64)
+        .run();
+    world
+        .check_account(ASKER)
+        .balance(8 * MIN_EGLD_LOCKED);
+
+    world
+        .tx()
+        .from(ASKER)
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .withdraw_sponsor_pool(1u64)
+        .returns(ExpectError(4, "Only sponsor can withdraw"))
+        .run();
+    world
+        .tx()
+        .from(SPAMMER)
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .withdraw_sponsor_pool(1u64)
+        .run();
+    world
+        .check_account(SPAMMER)
+        .balance(9 * MIN_EGLD_LOCKED);
+    let pools = world
+        .query()
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .get_pools_by_tag("sdk")
+        .returns(ReturnsResult)
+        .run();
+    assert_eq!(pools.len(), 0);
+}
+
+#[test]
+fn sponsor_pools_per_tag_are_capped_test() {
+    let mut world = stacktoken_deploy();
+
+    for sponsor in [OWNER, ASKER] {
+        for _ in 0..MAX_POOLS_PER_TAG / 2 {
+            world
+                .tx()
+                .from(sponsor)
+                .to(STACKTOKEN_ADDRESS)
+                .typed(stacktoken_proxy::StackTokenContractProxy)
+                .create_sponsor_pool("sdk", MIN_EGLD_LOCKED, 0u64)
+                .egld(MIN_EGLD_LOCKED)
+                .run();
+        }
+    }
+    world
+        .tx()
+        .from(SPAMMER)
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .create_sponsor_pool("sdk", MIN_EGLD_LOCKED, 0u64)
+        .egld(MIN_EGLD_LOCKED)
+        .returns(ExpectError(4, "Too many sponsor pools for tag"))
+        .run();
+
+    // Withdrawing a pool frees its slot
+    world
+        .tx()
+        .from(OWNER)
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .withdraw_sponsor_pool(1u64)
+        .run();
+    world
+        .tx()
+        .from(SPAMMER)
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .create_sponsor_pool("sdk", MIN_EGLD_LOCKED, 0u64)
+        .egld(MIN_EGLD_LOCKED)
+        .returns(ExpectValue(11u64))
+        .run();
+}
+
+#[test]
+fn milestone_bounty_test() {
+    let mut world = stacktoken_deploy();
+
+    let milestones = MultiValueVec::from(vec![
+        MultiValue2::from((ManagedBuffer::<StaticApi>::from("design"), BigUint::<StaticApi>::from(MIN_EGLD_LOCKED))),
+        MultiValue2::from((ManagedBuffer::<StaticApi>::from("build"), BigUint::<StaticApi>::from(2 * MIN_EGLD_LOCKED))),
+    ]);
+    world
+        .tx()
+        .from(ASKER)
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .post_milestone_question("title", "description", 1_000u64, milestones.clone())
+        .egld(2 * MIN_EGLD_LOCKED)
+        .returns(ExpectError(4, "Milestones must add up to the payment"))
+        .run();
+    world
+        .tx()
+        .from(ASKER)
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .post_milestone_question("title", "description", 1_000u64, milestones)
+        .egld(3 * MIN_EGLD_LOCKED)
+        .returns(ExpectValue(1u64))
+        .run();
+    world
+        .tx()
+        .from(ASKER)
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .set_reviewers(1u64, 1u32, MultiValueVec::from(vec![OWNER.to_address()]))
+        .returns(ExpectError(4, "Milestone questions cannot have reviewers"))
+        .run();
+    world
+        .tx()
+        .from(ASKER)
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .set_vesting_period(1u64, 1_000u64)
+        .returns(ExpectError(4, "Milestone questions cannot vest"))
+        .run();
+    world
+        .tx()
+        .from(ANSWERER)
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .submit_answer(1u64, "title", "description")
+        .run();
+
+    world
+        .tx()
+        .from(ASKER)
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .approve_answer(1u64, 1u64)
+        .returns(ExpectError(4, "Approve milestones individually"))
+        .run();
+    world
+        .tx()
+        .from(ASKER)
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .approve_milestone(1u64, 1usize, 1u64)
+        .run();
+    world
+        .check_account(ANSWERER)
+        .balance(11 * MIN_EGLD_LOCKED);
+
+    // The unapproved milestone goes back to the creator on expiry
+    world.current_block().block_timestamp(1_000);
+    world
+        .tx()
+        .from(ASKER)
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .refund_question(1u64)
+        .run();
+    world
+        .check_account(ASKER)
+        .balance(9 * MIN_EGLD_LOCKED);
+
+    let statuses: Vec<_> = world
+        .query()
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .get_milestones(1u64)
+        .returns(ReturnsResult)
+        .run()
+        .into_iter()
+        .map(|milestone| milestone.status)
+        .collect();
+    assert_eq!(
+        statuses,
+        vec![stacktoken_proxy::MilestoneStatus::Approved, stacktoken_proxy::MilestoneStatus::Refunded]
+    );
+}
+
+#[test]
+fn assigned_job_auto_releases_after_review_window_test() {
+    let mut world = stacktoken_deploy();
+    post_question(&mut world, 1_000);
+    post_question(&mut world, 1_000);
+
+    world
+        .tx()
+        .from(ASKER)
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .assign_question(1u64, ANSWERER, MAX_REVIEW_WINDOW + 1)
+        .returns(ExpectError(4, "Review window too long"))
+        .run();
+    for qid in [1u64, 2] {
+        world
+            .tx()
+            .from(ASKER)
+            .to(STACKTOKEN_ADDRESS)
+            .typed(stacktoken_proxy::StackTokenContractProxy)
+            .assign_question(qid, ANSWERER, 100u64)
+            .run();
+        world
+            .tx()
+            .from(ANSWERER)
+            .to(STACKTOKEN_ADDRESS)
+            .typed(stacktoken_proxy::StackTokenContractProxy)
+            .accept_assignment(qid)
+            .run();
+    }
+    world
+        .tx()
+        .from(SPAMMER)
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .submit_answer(1u64, "title", "description")
+        .returns(ExpectError(4, "Question is closed"))
+        .run();
+
+    world.current_block().block_timestamp(500);
+    for qid in [1u64, 2] {
+        world
+            .tx()
+            .from(ANSWERER)
+            .to(STACKTOKEN_ADDRESS)
+            .typed(stacktoken_proxy::StackTokenContractProxy)
+            .mark_delivered(qid, "title", "description")
+            .run();
+    }
+    world
+        .tx()
+        .from(ASKER)
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .dispute_delivery(2u64)
+        .run();
+    world
+        .tx()
+        .from(OWNER)
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .release_delivery(1u64)
+        .returns(ExpectError(4, "Review window still open"))
+        .run();
+
+    // The undisputed delivery is released once the review window ends
+    world.current_block().block_timestamp(600);
+    world
+        .tx()
+        .from(OWNER)
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .release_delivery(1u64)
+        .run();
+    world
+        .check_account(ANSWERER)
+        .balance(11 * MIN_EGLD_LOCKED);
+
+    // The disputed one waits for a moderator
+    world
+        .tx()
+        .from(OWNER)
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .release_delivery(2u64)
+        .returns(ExpectError(4, "Job is not delivered"))
+        .run();
+    run_admin_action(&mut world, AdminAction::AddModerator(SPAMMER.to_address().into()));
+    world
+        .tx()
+        .from(SPAMMER)
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .resolve_dispute(2u64, false)
+        .run();
+    world
+        .check_account(ASKER)
+        .balance(9 * MIN_EGLD_LOCKED);
+}
+
+#[test]
+fn emergency_refund_covers_jobs_under_review_test() {
+    let mut world = stacktoken_deploy();
+    post_question(&mut world, 1_000);
+    post_question(&mut world, 1_000);
+
+    for qid in [1u64, 2] {
+        world
+            .tx()
+            .from(ASKER)
+            .to(STACKTOKEN_ADDRESS)
+            .typed(stacktoken_proxy::StackTokenContractProxy)
+            .assign_question(qid, ANSWERER, 100u64)
+            .run();
+        world
+            .tx()
+            .from(ANSWERER)
+            .to(STACKTOKEN_ADDRESS)
+            .typed(stacktoken_proxy::StackTokenContractProxy)
+            .accept_assignment(qid)
+            .run();
+        world
+            .tx()
+            .from(ANSWERER)
+            .to(STACKTOKEN_ADDRESS)
+            .typed(stacktoken_proxy::StackTokenContractProxy)
+            .mark_delivered(qid, "title", "description")
+            .run();
+    }
+    world
+        .tx()
+        .from(ASKER)
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .dispute_delivery(2u64)
+        .run();
+
+    world
+        .tx()
+        .from(OWNER)
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .pause_contract()
+        .run();
+    world
+        .tx()
+        .from(OWNER)
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .emergency_refund_batch(10u64)
+        .returns(ExpectValue(2u64))
+        .run();
+    world
+        .tx()
+        .from(ASKER)
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .claim()
+        .run();
+    world
+        .check_account(ASKER)
+        .balance(10 * MIN_EGLD_LOCKED);
+}
+
+#[test]
+fn reviewers_approve_answer_m_of_n_test() {
+    let mut world = stacktoken_deploy();
+    post_question(&mut world, 1_000);
+
+    let reviewers = MultiValueVec::from(vec![OWNER.to_address(), SPAMMER.to_address(), TREASURY.to_address()]);
+    world
+        .tx()
+        .from(ASKER)
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .set_reviewers(1u64, 4u32, reviewers.clone())
+        .returns(ExpectError(4, "Invalid required approvals"))
+        .run();
+    world
+        .tx()
+        .from(ASKER)
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .set_reviewers(1u64, 2u32, reviewers)
+        .run();
+    world
+        .tx()
+        .from(ANSWERER)
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .submit_answer(1u64, "title", "description")
+        .run();
+
+    world
+        .tx()
+        .from(ASKER)
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .approve_answer(1u64, 1u64)
+        .returns(ExpectError(4, "Only reviewer can approve"))
+        .run();
+    world
+        .tx()
+        .from(OWNER)
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .approve_answer(1u64, 1u64)
+        .run();
+    world
+        .tx()
+        .from(OWNER)
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .approve_answer(1u64, 1u64)
+        .returns(ExpectError(4, "Already approved"))
+        .run();
+    world
+        .check_account(ANSWERER)
+        .balance(10 * MIN_EGLD_LOCKED);
+
+    // A reviewer who answers cannot count towards their own approval
+    world
+        .tx()
+        .from(SPAMMER)
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .submit_answer(1u64, "title", "reviewer answer")
+        .run();
+    world
+        .tx()
+        .from(SPAMMER)
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .approve_answer(1u64, 2u64)
+        .returns(ExpectError(4, "Reviewer cannot approve own answer"))
+        .run();
+
+    world
+        .tx()
+        .from(SPAMMER)
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .approve_answer(1u64, 1u64)
+        .run();
+    world
+        .check_account(ANSWERER)
+        .balance(11 * MIN_EGLD_LOCKED);
+}
+
+#[test]
+fn invite_only_question_test() {
+    let mut world = stacktoken_deploy();
+    world
+        .tx()
+        .from(ASKER)
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .post_invite_only_question("title", "description", 1_000u64, MultiValueVec::from(vec![ANSWERER.to_address()]))
+        .egld(MIN_EGLD_LOCKED)
+        .run();
+
+    world
+        .tx()
+        .from(SPAMMER)
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .submit_answer(1u64, "title", "description")
+        .returns(ExpectError(4, "Not invited to answer"))
+        .run();
+    world
+        .tx()
+        .from(ANSWERER)
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .submit_answer(1u64, "title", "description")
+        .run();
+
+    world
+        .tx()
+        .from(ASKER)
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .add_allowed_answerers(1u64, MultiValueVec::from(vec![SPAMMER.to_address()]))
+        .run();
+    world
+        .tx()
+        .from(ASKER)
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .remove_allowed_answerers(1u64, MultiValueVec::from(vec![ANSWERER.to_address()]))
+        .run();
+    for (address, allowed) in [(SPAMMER, true), (ANSWERER, false), (ASKER, false)] {
+        world
+            .query()
+            .to(STACKTOKEN_ADDRESS)
+            .typed(stacktoken_proxy::StackTokenContractProxy)
+            .can_answer(1u64, address)
+            .returns(ExpectValue(allowed))
+            .run();
+    }
+}
+
+#[test]
+fn puzzle_question_pays_first_valid_reveal_test() {
+    let mut world = stacktoken_deploy();
+    let answer_hash = ManagedByteArray::<StaticApi, 32>::new_from_bytes(
+        &multiversx_sc_scenario::multiversx_chain_vm::crypto_functions::sha256(b"42"),
+    );
+    world
+        .tx()
+        .from(ASKER)
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .post_puzzle_question("title", "description", 1_000u64, answer_hash)
+        .egld(MIN_EGLD_LOCKED)
+        .run();
+
+    world
+        .tx()
+        .from(ANSWERER)
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .submit_answer(1u64, "title", "description")
+        .returns(ExpectError(4, "Use solvePuzzle for puzzle questions"))
+        .run();
+
+    for (solver, preimage) in [(ANSWERER, "42"), (SPAMMER, "41")] {
+        let commitment = world
+            .query()
+            .to(STACKTOKEN_ADDRESS)
+            .typed(stacktoken_proxy::StackTokenContractProxy)
+            .compute_puzzle_commitment(solver, preimage)
+            .returns(ReturnsResult)
+            .run();
+        world
+            .tx()
+            .from(solver)
+            .to(STACKTOKEN_ADDRESS)
+            .typed(stacktoken_proxy::StackTokenContractProxy)
+            .commit_puzzle_solution(1u64, commitment)
+            .run();
+    }
+    world
+        .tx()
+        .from(ANSWERER)
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .solve_puzzle(1u64, "42")
+        .returns(ExpectError(4, "Reveal must happen after the commit block"))
+        .run();
+
+    world.current_block().block_nonce(1);
+    world
+        .tx()
+        .from(SPAMMER)
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .solve_puzzle(1u64, "41")
+        .returns(ExpectError(4, "Wrong solution"))
+        .run();
+    // Replaying someone else's preimage doesn't match the replayer's own commitment
+    world
+        .tx()
+        .from(SPAMMER)
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .solve_puzzle(1u64, "42")
+        .returns(ExpectError(4, "Commitment mismatch"))
+        .run();
+    world
+        .tx()
+        .from(ANSWERER)
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .solve_puzzle(1u64, "42")
+        .run();
+    world
+        .check_account(ANSWERER)
+        .balance(11 * MIN_EGLD_LOCKED);
+}
+
+#[test]
+fn competition_pays_ranked_winners_test() {
+    let mut world = stacktoken_deploy();
+    world
+        .tx()
+        .from(ASKER)
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .post_competition_question("title", "description", 1_000u64, MultiValueVec::from(vec![6_000u64, 3_000]))
+        .egld(MIN_EGLD_LOCKED)
+        .returns(ExpectError(4, "Payout schedule must add up to 100%"))
+        .run();
+    world
+        .tx()
+        .from(ASKER)
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .post_competition_question("title", "description", 1_000u64, MultiValueVec::from(vec![6_000u64, 3_000, 1_000]))
+        .egld(MIN_EGLD_LOCKED)
+        .run();
+    post_question(&mut world, 1_000);
+    world
+        .tx()
+        .from(ASKER)
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .set_reviewers(1u64, 1u32, MultiValueVec::from(vec![OWNER.to_address()]))
+        .returns(ExpectError(4, "Competition questions cannot have reviewers"))
+        .run();
+    world
+        .tx()
+        .from(ASKER)
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .set_vesting_period(1u64, 1_000u64)
+        .returns(ExpectError(4, "Competition questions cannot vest"))
+        .run();
+
+    for (answerer, qid, description) in [(ANSWERER, 1u64, "first"), (SPAMMER, 1, "second"), (ANSWERER, 2, "third")] {
+        world
+            .tx()
+            .from(answerer)
+            .to(STACKTOKEN_ADDRESS)
+            .typed(stacktoken_proxy::StackTokenContractProxy)
+            .submit_answer(qid, "title", description)
+            .run();
+    }
+
+    world
+        .tx()
+        .from(ASKER)
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .approve_answer(1u64, 1u64)
+        .returns(ExpectError(4, "Use rankAnswers for competition questions"))
+        .run();
+    world
+        .tx()
+        .from(ASKER)
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .rank_answers(1u64, MultiValueVec::from(vec![1u64, 3]))
+        .returns(ExpectError(4, "Answer does not match question"))
+        .run();
+    world
+        .tx()
+        .from(ASKER)
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .rank_answers(1u64, MultiValueVec::from(vec![2u64, 2]))
+        .returns(ExpectError(4, "Answer ranked twice"))
+        .run();
+    world
+        .tx()
+        .from(ASKER)
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .rank_answers(1u64, MultiValueVec::from(vec![2u64]))
+        .returns(ExpectError(4, "All payout places must be ranked"))
+        .run();
+    world
+        .tx()
+        .from(ASKER)
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .rank_answers(1u64, MultiValueVec::from(vec![2u64, 1]))
+        .run();
+
+    // The third place had no answer to fill it, so its share goes back to the creator
+    world
+        .check_account(SPAMMER)
+        .balance(10 * MIN_EGLD_LOCKED + MIN_EGLD_LOCKED * 6 / 10);
+    world
+        .check_account(ANSWERER)
+        .balance(10 * MIN_EGLD_LOCKED + MIN_EGLD_LOCKED * 3 / 10);
+    world
+        .check_account(ASKER)
+        .balance(8 * MIN_EGLD_LOCKED + MIN_EGLD_LOCKED / 10);
+}
+
+#[test]
+fn decaying_bounty_pays_less_for_late_answers_test() {
+    let mut world = stacktoken_deploy();
+    world
+        .tx()
+        .from(ASKER)
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .post_decaying_question(
+            "title",
+            "description",
+            1_000u64,
+            stacktoken_proxy::DecayCurve::Linear { duration: 400, min_payout_bps: 2_000 },
+        )
+        .egld(MIN_EGLD_LOCKED)
+        .run();
+
+    // Half-way through the decay the answer earns 60% of the bounty
+    world.current_block().block_timestamp(200);
+    world
+        .query()
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .get_current_payout(1u64)
+        .returns(ExpectValue(BigUint::<StaticApi>::from(MIN_EGLD_LOCKED * 6 / 10)))
+        .run();
+    world
+        .tx()
+        .from(ANSWERER)
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .submit_answer(1u64, "title", "description")
+        .run();
+
+    // Approving later doesn't change what the answer earned
+    world.current_block().block_timestamp(900);
+    world
+        .query()
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .get_current_payout(1u64)
+        .returns(ExpectValue(BigUint::<StaticApi>::from(MIN_EGLD_LOCKED * 2 / 10)))
+        .run();
+    world
+        .tx()
+        .from(ASKER)
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .approve_answer(1u64, 1u64)
+        .run();
+    world
+        .check_account(ANSWERER)
+        .balance(10 * MIN_EGLD_LOCKED + MIN_EGLD_LOCKED * 6 / 10);
+    world
+        .check_account(ASKER)
+        .balance(9 * MIN_EGLD_LOCKED + MIN_EGLD_LOCKED * 4 / 10);
+}
+
+#[test]
+fn vested_payout_can_be_frozen_by_dispute_test() {
+    let mut world = stacktoken_deploy();
+    post_question(&mut world, 1_000);
+    world
+        .tx()
+        .from(ASKER)
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .set_vesting_period(1u64, MAX_VESTING_PERIOD + 1)
+        .returns(ExpectError(4, "Vesting period too long"))
+        .run();
+    world
+        .tx()
+        .from(ASKER)
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .set_vesting_period(1u64, 1_000u64)
+        .run();
+    world
+        .tx()
+        .from(ANSWERER)
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .submit_answer(1u64, "title", "description")
+        .run();
+
+    world.current_block().block_timestamp(100);
+    world
+        .tx()
+        .from(ASKER)
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .approve_answer(1u64, 1u64)
+        .run();
+    world
+        .check_account(ANSWERER)
+        .balance(10 * MIN_EGLD_LOCKED);
+
+    // Half of the reward has vested when the creator disputes
+    world.current_block().block_timestamp(600);
+    world
+        .tx()
+        .from(ASKER)
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .dispute_vesting(1u64)
+        .run();
+
+    world.current_block().block_timestamp(1_100);
+    world
+        .query()
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .get_vested_claimable(1u64)
+        .returns(ExpectValue(BigUint::<StaticApi>::from(MIN_EGLD_LOCKED / 2)))
+        .run();
+    // Vested claims honour the pull-payout opt-in
+    world
+        .tx()
+        .from(ANSWERER)
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .set_pull_payouts(true)
+        .run();
+    world
+        .tx()
+        .from(ANSWERER)
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .claim_vested(1u64)
+        .run();
+    world
+        .check_account(ANSWERER)
+        .balance(10 * MIN_EGLD_LOCKED);
+    world
+        .tx()
+        .from(ANSWERER)
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .claim()
+        .run();
+    world
+        .check_account(ANSWERER)
+        .balance(10 * MIN_EGLD_LOCKED + MIN_EGLD_LOCKED / 2);
+
+    run_admin_action(&mut world, AdminAction::AddModerator(OWNER.to_address().into()));
+    world
+        .tx()
+        .from(OWNER)
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .resolve_vesting_dispute(1u64, false)
+        .run();
+    world
+        .check_account(ASKER)
+        .balance(9 * MIN_EGLD_LOCKED + MIN_EGLD_LOCKED / 2);
+    world
+        .tx()
+        .from(ANSWERER)
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .claim_vested(1u64)
+        .returns(ExpectError(4, "Nothing to claim"))
+        .run();
+}
+
+#[test]
+fn vesting_can_only_be_disputed_once_test() {
+    let mut world = stacktoken_deploy();
+    post_question(&mut world, 1_000);
+    world
+        .tx()
+        .from(ASKER)
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .set_vesting_period(1u64, 1_000u64)
+        .run();
+    world
+        .tx()
+        .from(ANSWERER)
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .submit_answer(1u64, "title", "description")
+        .run();
+    world
+        .tx()
+        .from(ASKER)
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .approve_answer(1u64, 1u64)
+        .run();
+
+    world.current_block().block_timestamp(100);
+    world
+        .tx()
+        .from(ASKER)
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .dispute_vesting(1u64)
+        .run();
+    run_admin_action(&mut world, AdminAction::AddModerator(OWNER.to_address().into()));
+    world
+        .tx()
+        .from(OWNER)
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .resolve_vesting_dispute(1u64, true)
+        .run();
+
+    // The ruling for the answerer cannot be reopened by disputing again
+    world
+        .tx()
+        .from(ASKER)
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .dispute_vesting(1u64)
+        .returns(ExpectError(4, "Vesting dispute already resolved"))
+        .run();
+
+    world.current_block().block_timestamp(1_000);
+    world
+        .tx()
+        .from(ANSWERER)
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .claim_vested(1u64)
+        .run();
+    world
+        .check_account(ANSWERER)
+        .balance(11 * MIN_EGLD_LOCKED);
+}
+
+#[test]
+fn comments_are_paginated_and_can_be_hidden_test() {
+    let mut world = stacktoken_deploy();
+    post_question(&mut world, 1_000);
+    let target = stacktoken_proxy::CommentTarget::Question(1);
+
+    world
+        .tx()
+        .from(ANSWERER)
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .add_comment(stacktoken_proxy::CommentTarget::Answer(1), "which version?", OptionalValue::<u64>::None)
+        .returns(ExpectError(4, "Answer does not exist"))
+        .run();
+    world
+        .tx()
+        .from(ANSWERER)
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .add_comment(target.clone(), "which version?", OptionalValue::<u64>::None)
+        .returns(ExpectValue(1u64))
+        .run();
+    world
+        .tx()
+        .from(ASKER)
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .add_comment(target.clone(), "the latest one", OptionalValue::Some(1u64))
+        .run();
+    world
+        .tx()
+        .from(SPAMMER)
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .add_comment(target.clone(), "buy my course", OptionalValue::<u64>::None)
+        .run();
+
+    run_admin_action(&mut world, AdminAction::AddModerator(OWNER.to_address().into()));
+    world
+        .tx()
+        .from(OWNER)
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .set_comment_hidden(3u64, true)
+        .run();
+
+    let first_page: Vec<_> = world
+        .query()
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .get_comments(target.clone(), 0usize, 2usize)
+        .returns(ReturnsResult)
+        .run()
+        .into_iter()
+        .collect();
+    assert_eq!(first_page.len(), 2);
+    assert_eq!(first_page[1].reply_to, Some(1));
+
+    let second_page = world
+        .query()
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .get_comments(target.clone(), 2usize, 2usize)
+        .returns(ReturnsResult)
+        .run();
+    assert!(second_page.is_empty());
+
+    // Offsets past the end return an empty page instead of failing
+    let past_end = world
+        .query()
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .get_comments(target, u32::MAX as usize, 2usize)
+        .returns(ReturnsResult)
+        .run();
+    assert!(past_end.is_empty());
+}
+
+#[test]
+fn close_as_duplicate_refunds_or_moves_bounty_test() {
+    let mut world = stacktoken_deploy();
+    post_question(&mut world, 1_000);
+    post_question(&mut world, 1_000);
+    post_question(&mut world, 1_000);
+
+    world
+        .tx()
+        .from(ANSWERER)
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .close_as_duplicate(2u64, 1u64, false)
+        .returns(ExpectError(4, "Only creator or moderator can close as duplicate"))
+        .run();
+
+    // Once answered, the creator can no longer take the bounty back through a duplicate closure
+    world
+        .tx()
+        .from(ANSWERER)
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .submit_answer(2u64, "title", "description")
+        .run();
+    world
+        .tx()
+        .from(ASKER)
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .close_as_duplicate(2u64, 1u64, false)
+        .returns(ExpectError(4, "Only moderator can refund an answered or puzzle question"))
+        .run();
+
+    // A moderator can close it, but only the creator decides to move the bounty
+    run_admin_action(&mut world, AdminAction::AddModerator(OWNER.to_address().into()));
+    world
+        .tx()
+        .from(OWNER)
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .close_as_duplicate(2u64, 1u64, true)
+        .returns(ExpectError(4, "Only creator can move the bounty"))
+        .run();
+    world
+        .tx()
+        .from(OWNER)
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .close_as_duplicate(2u64, 1u64, false)
+        .run();
+    world
+        .check_account(ASKER)
+        .balance(8 * MIN_EGLD_LOCKED);
+
+    world
+        .tx()
+        .from(ASKER)
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .close_as_duplicate(3u64, 2u64, true)
+        .returns(ExpectError(4, "Canonical question is itself a duplicate"))
+        .run();
+    world
+        .tx()
+        .from(ASKER)
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .close_as_duplicate(3u64, 1u64, true)
+        .run();
+
+    let duplicate = world
+        .query()
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .get_question_details(3u64)
+        .returns(ReturnsResult)
+        .run()
+        .into_option()
+        .unwrap();
+    assert!(duplicate.status == stacktoken_proxy::QuestionStatus::Duplicate);
+    assert_eq!(duplicate.duplicate_of, Some(1));
+    let canonical = world
+        .query()
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .get_question_details(1u64)
+        .returns(ReturnsResult)
+        .run()
+        .into_option()
+        .unwrap();
+    assert_eq!(canonical.locked_amount, BigUint::from(2 * MIN_EGLD_LOCKED));
+
+    // A bounty cannot be moved into a question that is already past its deadline
+    world.current_block().block_timestamp(1_000);
+    post_question(&mut world, 2_000);
+    world
+        .tx()
+        .from(ASKER)
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .close_as_duplicate(4u64, 1u64, true)
+        .returns(ExpectError(4, "Canonical question deadline has passed"))
+        .run();
+}
+
+#[test]
+fn profiles_have_unique_names_and_skills_directory_test() {
+    let mut world = stacktoken_deploy();
+
+    world
+        .tx()
+        .from(ANSWERER)
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .set_profile("Alice", "ipfs://bio", MultiValueVec::from(vec![ManagedBuffer::<StaticApi>::from("rust")]))
+        .run();
+    world
+        .tx()
+        .from(SPAMMER)
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .set_profile(" alice ", "", MultiValueVec::<ManagedBuffer<StaticApi>>::new())
+        .returns(ExpectError(4, "Display name already taken"))
+        .run();
+
+    // Renaming frees the old name and replaces the skills
+    world
+        .tx()
+        .from(ANSWERER)
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .set_profile("Alice Dev", "ipfs://bio", MultiValueVec::from(vec![ManagedBuffer::<StaticApi>::from("sdk")]))
+        .run();
+    world
+        .tx()
+        .from(SPAMMER)
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .set_profile("Alice", "", MultiValueVec::from(vec![ManagedBuffer::<StaticApi>::from("sdk")]))
+        .run();
+
+    world
+        .query()
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .get_users_by_skill("rust")
+        .returns(ExpectValue(MultiValueVec::<ManagedAddress<StaticApi>>::new()))
+        .run();
+    let sdk_users = world
+        .query()
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .get_users_by_skill("sdk")
+        .returns(ReturnsResult)
+        .run();
+    assert_eq!(sdk_users.len(), 2);
+    let profile = world
+        .query()
+        .to(STACKTOKEN_ADDRESS)
+        .typed(stacktoken_proxy::StackTokenContractProxy)
+        .get_profile(ANSWERER)
+        .returns(ReturnsResult)
+        .run()
+        .into_option()
+        .unwrap();
+    assert_eq!(profile.display_name, ManagedBuffer::from("Alice Dev"));
+}
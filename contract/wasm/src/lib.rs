@@ -5,9 +5,9 @@
 ////////////////////////////////////////////////////
 
 // Init:                                 1
-// Endpoints:                           16
-// Async Callback (empty):               1
-// Total number of exported functions:  18
+// Endpoints:                          140
+// Async Callback:                       1
+// Total number of exported functions: 142
 
 #![no_std]
 
@@ -19,22 +19,146 @@ multiversx_sc_wasm_adapter::endpoints! {
     (
         init => init
         postQuestion => post_question
+        postMilestoneQuestion => post_milestone_question
+        postInviteOnlyQuestion => post_invite_only_question
+        postPuzzleQuestion => post_puzzle_question
+        postCompetitionQuestion => post_competition_question
+        postDecayingQuestion => post_decaying_question
         submitAnswer => submit_answer
+        commitAnswer => commit_answer
+        revealAnswer => reveal_answer
+        enableCommitReveal => enable_commit_reveal
+        voteAnswer => vote_answer
+        addComment => add_comment
+        setCommentHidden => set_comment_hidden
+        getComments => get_comments
+        getCommentCount => get_comment_count
+        setProfile => set_profile
+        getProfile => get_profile
+        getUsersBySkill => get_users_by_skill
+        getAddressByDisplayName => get_address_by_display_name
+        tipAnswer => tip_answer
+        createSponsorPool => create_sponsor_pool
+        fundSponsorPool => fund_sponsor_pool
+        withdrawSponsorPool => withdraw_sponsor_pool
+        getSponsorPool => get_sponsor_pool
+        getPoolsByTag => get_pools_by_tag
+        getPoolMatches => get_pool_matches
+        getQuestionTags => get_question_tags
+        getAnswerTips => get_answer_tips
         approveAnswer => approve_answer
+        setReviewers => set_reviewers
+        getCurrentPayout => get_current_payout
+        getBountyDecay => get_bounty_decay
+        rankAnswers => rank_answers
+        getPayoutSchedule => get_payout_schedule
+        setVestingPeriod => set_vesting_period
+        claimVested => claim_vested
+        disputeVesting => dispute_vesting
+        resolveVestingDispute => resolve_vesting_dispute
+        getVesting => get_vesting
+        getVestedClaimable => get_vested_claimable
+        getVestingPeriod => get_vesting_period
+        approveMilestone => approve_milestone
+        getMilestones => get_milestones
         refundQuestion => refund_question
         expireQuestions => expire_questions
+        addAllowedAnswerers => add_allowed_answerers
+        removeAllowedAnswerers => remove_allowed_answerers
+        canAnswer => can_answer
+        getAllowedAnswerers => get_allowed_answerers
+        commitPuzzleSolution => commit_puzzle_solution
+        solvePuzzle => solve_puzzle
+        computePuzzleCommitment => compute_puzzle_commitment
+        getPuzzleHash => get_puzzle_hash
+        closeAsDuplicate => close_as_duplicate
+        assignQuestion => assign_question
+        acceptAssignment => accept_assignment
+        markDelivered => mark_delivered
+        disputeDelivery => dispute_delivery
+        releaseDelivery => release_delivery
+        resolveDispute => resolve_dispute
+        getReviewers => get_reviewers
+        getReviewerApprovals => get_reviewer_approvals
+        getJobAssignment => get_job_assignment
+        setAnswerStake => set_answer_stake
+        markAnswerAsSpam => mark_answer_as_spam
+        claim => claim
+        setPullPayouts => set_pull_payouts
+        issueStackToken => issue_stack_token
+        setStackTokenRewards => set_stack_token_rewards
+        getStackTokenId => get_stack_token_id
+        getStackTokenRewards => get_stack_token_rewards
+        stake => stake
+        unstake => unstake
+        withdrawUnstaked => withdraw_unstaked
+        setUnbondingPeriod => set_unbonding_period
+        setVoteWeightParams => set_vote_weight_params
+        getVoteWeight => get_vote_weight
+        getPlatformFeeFor => get_platform_fee_for
+        getStakedAmount => get_staked_amount
+        getStakeLockedUntil => get_stake_locked_until
+        getUnbonding => get_unbonding
+        getTotalStaked => get_total_staked
+        getPlatformFee => get_platform_fee
+        proposeParameterChange => propose_parameter_change
+        voteProposal => vote_proposal
+        executeProposal => execute_proposal
+        setGovernanceParams => set_governance_params
+        getMinBounty => get_min_bounty
+        getDeadlineBounds => get_deadline_bounds
+        getProposal => get_proposal
+        getProposalStatus => get_proposal_status
+        getActiveProposals => get_active_proposals
+        getProposalVotes => get_proposal_votes
+        issueBadgeCollection => issue_badge_collection
+        addBadge => add_badge
+        awardBadge => award_badge
+        claimBadges => claim_badges
+        getPendingBadges => get_pending_badges
+        getBadges => get_badges
+        getBadgeRegistry => get_badge_registry
+        getContributionStats => get_contribution_stats
+        getClaimable => get_claimable
+        isPullPayoutsEnabled => is_pull_payouts_enabled
+        getAnswerStake => get_answer_stake
+        getAnswerStakeDeposit => get_answer_stake_deposit
+        isAnswerSpam => is_answer_spam
+        getRevealPeriod => get_reveal_period
+        computeAnswerCommitment => compute_answer_commitment
+        isAnswerRevealed => is_answer_revealed
+        computeContentHash => compute_content_hash
+        getAnswerContentHash => get_answer_content_hash
+        getContentHashFirstAuthor => get_content_hash_first_author
+        getTreasury => get_treasury
         getAllOpenQuestions => get_all_open_questions
         getQuestionDetails => get_question_details
         getAnswersForQuestion => get_answers_for_question
         pauseContract => pause_contract
         unpauseContract => unpause_contract
-        transferOwnership => transfer_ownership
+        emergencyRefundBatch => emergency_refund_batch
+        getEmergencyRefundProgress => get_emergency_refund_progress
+        queueAdminAction => queue_admin_action
+        executeAdminAction => execute_admin_action
+        cancelAdminAction => cancel_admin_action
+        getPendingAdminActions => get_pending_admin_actions
+        getAdminTimelockDelay => get_admin_timelock_delay
+        addToDenylist => add_to_denylist
+        removeFromDenylist => remove_from_denylist
+        setUserLimits => set_user_limits
+        getModerators => get_moderators
+        isModerator => is_moderator
+        isDenylisted => is_denylisted
+        getUserLimits => get_user_limits
+        getOpenQuestionCount => get_open_question_count
         getOwner => get_owner
         isPaused => is_contract_paused
         getTotalQuestions => get_total_questions
         getTotalAnswers => get_total_answers
         getContractStats => get_contract_stats
+        getTokenTotals => get_token_totals
+        checkSolvency => check_solvency
     )
 }
 
-multiversx_sc_wasm_adapter::async_callback_empty! {}
+multiversx_sc_wasm_adapter::async_callback! { stacktoken }